@@ -0,0 +1,87 @@
+// Sample implementation of a constant product pool the DAO wallet can swap on and provide
+// liquidity to
+
+use scrypto::prelude::*;
+
+#[blueprint]
+mod ConstantProductPool {
+    struct ConstantProductPool {
+        vault_a: Vault,
+        vault_b: Vault,
+        pool_units_resource_manager: ResourceManager,
+    }
+
+    impl ConstantProductPool {
+        pub fn new(
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+        ) -> (Global<ConstantProductPool>, Bucket) {
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(ConstantProductPool::blueprint_id());
+
+            let pool_units_bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .mint_initial_supply(bucket_a.amount());
+
+            let pool = Self {
+                vault_a: Vault::with_bucket(bucket_a),
+                vault_b: Vault::with_bucket(bucket_b),
+                pool_units_resource_manager: pool_units_bucket.resource_manager().into(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .with_address(address_reservation)
+            .globalize();
+
+            (pool, pool_units_bucket.into())
+        }
+
+        pub fn add_liquidity(
+            &mut self,
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+        ) -> Bucket {
+            let pool_units = self.pool_units_resource_manager.total_supply().unwrap()
+                * bucket_a.amount() / self.vault_a.amount();
+
+            self.vault_a.put(bucket_a);
+            self.vault_b.put(bucket_b);
+
+            self.pool_units_resource_manager.mint(pool_units)
+        }
+
+        pub fn swap(
+            &mut self,
+            input: Bucket,
+        ) -> Bucket {
+            let (input_vault, output_vault) =
+                match input.resource_address() == self.vault_a.resource_address() {
+                    true => (&mut self.vault_a, &mut self.vault_b),
+                    false => (&mut self.vault_b, &mut self.vault_a),
+                };
+
+            let output = output_vault.amount() * input.amount()
+                / (input_vault.amount() + input.amount());
+
+            input_vault.put(input);
+            output_vault.take_advanced(output, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+        }
+
+        pub fn swap_with_min_output(
+            &mut self,
+            input: Bucket,
+            min_output: Decimal,
+        ) -> Bucket {
+            let output = self.swap(input);
+            assert!(
+                output.amount() >= min_output,
+                "Not enough output"
+            );
+
+            output
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use scrypto::prelude::*;
+use crate::operation::*;
+use crate::operation_details::*;
+
+/* An Action is one of the steps of a Batch operation; each action works like the operation of
+ * the same type.
+ */
+#[derive(ScryptoSbor, PartialEq, Clone)]
+pub enum Action {
+
+    // resource address, amount and recipient of the fungibles to send
+    SendFungibles(ResourceAddress, Decimal, Global<Account>),
+
+    // resource address, ids and recipient of the non fungibles to send
+    SendNonFungibles(ResourceAddress, Vec<NonFungibleLocalId>, Global<Account>),
+
+    // amount of XRD to stake and validator to stake to
+    Stake(Decimal, Global<Validator>),
+
+    // amount of LSU to unstake and validator to unstake from
+    Unstake(Decimal, Global<Validator>),
+
+    // id of the member badge to disable
+    DisableBadge(NonFungibleLocalId),
+
+    // id of the member badge to enable
+    EnableBadge(NonFungibleLocalId),
+
+    // id of the member badge to renew and new end of the member term
+    RenewMember(NonFungibleLocalId, Instant),
+}
+
+impl Action {
+
+    /* Get the operation equivalent to this action, so that it can be verified and executed the
+     * same way.
+     * Input parameters:
+     * - description: human readable description of the Batch operation
+     * Outputs:
+     * - the Operation object
+     */
+    pub fn to_operation(
+        &self,
+        description: &str,
+    ) -> Operation {
+        let details = match self.clone() {
+            Action::SendFungibles(resource, amount, recipient) =>
+                OperationDetails::SendFungibles(resource, amount, recipient),
+            Action::SendNonFungibles(resource, non_fungible_ids, recipient) =>
+                OperationDetails::SendNonFungibles(resource, non_fungible_ids, recipient),
+            Action::Stake(amount, validator) =>
+                OperationDetails::Stake(amount, validator),
+            Action::Unstake(amount, validator) =>
+                OperationDetails::Unstake(amount, validator),
+            Action::DisableBadge(non_fungible_id) =>
+                OperationDetails::DisableBadge(non_fungible_id),
+            Action::EnableBadge(non_fungible_id) =>
+                OperationDetails::EnableBadge(non_fungible_id),
+            Action::RenewMember(non_fungible_id, valid_until) =>
+                OperationDetails::RenewMember(non_fungible_id, valid_until),
+        };
+
+        Operation::new(
+            description.to_string(),
+            details,
+        )
+    }
+}
@@ -0,0 +1,123 @@
+use scrypto::prelude::*;
+use crate::budget::*;
+
+/* AllowanceBadge is the data of the non fungible badge that lets its holder, typically a payroll
+ * or subscription component, withdraw a fungible resource from the DAO treasury within limits.
+ * The data are informative only, the limits actually enforced are in the Allowance struct.
+ */
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct AllowanceBadge {
+
+    // the resource that can be withdrawn
+    resource: ResourceAddress,
+
+    // amount that can be withdrawn in each period
+    amount: Decimal,
+
+    // duration of a period in seconds
+    period: i64,
+
+    // the allowance can't be used after this date
+    expiration: Instant,
+}
+
+/* An Allowance is the state of an allowance badge: the resource it allows to withdraw and the
+ * Budget it can spend.
+ */
+#[derive(ScryptoSbor)]
+pub struct Allowance {
+
+    // the resource that can be withdrawn
+    resource: ResourceAddress,
+
+    // limits and spending of the allowance
+    budget: Budget,
+}
+
+/* This event is emitted each time an allowance badge holder withdraws from the DAO treasury.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AllowanceWithdrawnEvent {
+    allowance_id: NonFungibleLocalId,
+    resource: ResourceAddress,
+    amount: Decimal,
+    remaining: Decimal,
+}
+
+impl AllowanceBadge {
+
+    /* Create the data of a new allowance badge.
+     * Input parameters:
+     * - resource: the resource that can be withdrawn
+     * - amount: amount that can be withdrawn in each period
+     * - period: duration of a period in seconds
+     * - expiration: the allowance can't be used after this date
+     * Outputs:
+     * - an AllowanceBadge object
+     */
+    pub fn new(
+        resource: ResourceAddress,
+        amount: Decimal,
+        period: i64,
+        expiration: Instant,
+    ) -> AllowanceBadge {
+        AllowanceBadge {
+            resource,
+            amount,
+            period,
+            expiration,
+        }
+    }
+}
+
+impl Allowance {
+
+    /* Create a new Allowance struct, the first period starts now.
+     * Input parameters:
+     * - resource: the resource that can be withdrawn
+     * - amount: amount that can be withdrawn in each period
+     * - period: duration of a period in seconds
+     * - expiration: the allowance can't be used after this date
+     * Outputs:
+     * - an Allowance object
+     */
+    pub fn new(
+        resource: ResourceAddress,
+        amount: Decimal,
+        period: i64,
+        expiration: Instant,
+    ) -> Allowance {
+        Allowance {
+            resource,
+            budget: Budget::new(amount, period, expiration),
+        }
+    }
+
+    /* Register a withdrawal and emit an AllowanceWithdrawnEvent.
+     * The method panics if the allowance is expired or the amount exceeds what is left in the
+     * current period.
+     * Input parameters:
+     * - allowance_id: the non fungible id of the allowance badge
+     * - amount: the amount to withdraw
+     * Outputs:
+     * - the resource address to withdraw
+     */
+    pub fn withdraw(
+        &mut self,
+        allowance_id: NonFungibleLocalId,
+        amount: Decimal,
+    ) -> ResourceAddress {
+        let remaining = self.budget.consume(amount);
+
+        Runtime::emit_event(
+            AllowanceWithdrawnEvent {
+                allowance_id,
+                resource: self.resource,
+                amount,
+                remaining,
+            }
+        );
+
+        self.resource
+    }
+}
@@ -0,0 +1,120 @@
+use scrypto::prelude::*;
+
+/* An Application is a pending request of an outsider to become a member
+ */
+#[derive(ScryptoSbor)]
+pub struct Application {
+
+    // message of the candidate to the members
+    message: String,
+
+    // amount of XRD the candidate locked in the component as a bond
+    bond: Decimal,
+
+    // the application can't be approved after this date
+    expiration: Instant,
+}
+
+/* This event is emitted when an outsider applies for membership, it can be used as a call to
+ * action for members.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct NewApplicationEvent {
+    account: Global<Account>,
+    message: String,
+    bond: Decimal,
+    expiration: Instant,
+}
+
+/* This event is emitted when the candidate refuses the refund of the bond of a closed
+ * application; the bond is stored in the AccountLocker for him to claim.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct BondStoredEvent {
+    account: Global<Account>,
+    bond: Decimal,
+}
+
+impl Application {
+
+    /* Create a new Application struct and emit a NewApplicationEvent.
+     * Input parameters:
+     * - account: the account of the candidate
+     * - message: message of the candidate to the members
+     * - bond: amount of XRD the candidate locked as a bond
+     * - validity: number of seconds the application stays valid
+     * Outputs:
+     * - an Application object
+     */
+    pub fn new(
+        account: Global<Account>,
+        message: String,
+        bond: Decimal,
+        validity: i64,
+    ) -> Application {
+        let expiration = Clock::current_time_rounded_to_seconds().add_seconds(validity).unwrap();
+
+        Runtime::emit_event(
+            NewApplicationEvent {
+                account,
+                message: message.clone(),
+                bond,
+                expiration,
+            }
+        );
+
+        Application {
+            message,
+            bond,
+            expiration,
+        }
+    }
+
+    /* Get the message of the candidate.
+     * Outputs:
+     * - the message of the candidate to the members
+     */
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /* Get the expiration of this application.
+     * Outputs:
+     * - the date after which the application can't be approved
+     */
+    pub fn expiration(&self) -> Instant {
+        self.expiration
+    }
+
+    /* Get the amount of the bond of this application.
+     * Outputs:
+     * - the amount of XRD locked by the candidate
+     */
+    pub fn bond(&self) -> Decimal {
+        self.bond
+    }
+
+    /* Emit the BondStoredEvent.
+     * Input parameters:
+     * - account: the account of the candidate
+     */
+    pub fn emit_bond_stored_event(
+        &self,
+        account: Global<Account>,
+    ) {
+        Runtime::emit_event(
+            BondStoredEvent {
+                account,
+                bond: self.bond,
+            }
+        );
+    }
+
+    /* Check whether this application can no longer be approved.
+     * Outputs:
+     * - true if the expiration date is over
+     */
+    pub fn is_expired(&self) -> bool {
+        Clock::current_time_is_at_or_after(self.expiration, TimePrecision::Second)
+    }
+}
@@ -0,0 +1,128 @@
+use scrypto::prelude::*;
+
+/* A Budget is an amount of a fungible resource a member can spend from the DAO treasury in each
+ * period without the signatures of the other members
+ */
+#[derive(ScryptoSbor)]
+pub struct Budget {
+
+    // amount that can be spent in each period
+    amount: Decimal,
+
+    // duration of a period in seconds
+    period: i64,
+
+    // the budget can't be used after this date
+    expiration: Instant,
+
+    // start of the current period
+    period_start: Instant,
+
+    // amount already spent in the current period
+    spent: Decimal,
+}
+
+/* This event is emitted each time a member spends part of his budget.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct BudgetSpentEvent {
+    badge_id: NonFungibleLocalId,
+    resource: ResourceAddress,
+    amount: Decimal,
+    recipient: Global<Account>,
+    remaining: Decimal,
+}
+
+impl Budget {
+
+    /* Create a new Budget struct, the first period starts now.
+     * Input parameters:
+     * - amount: amount that can be spent in each period
+     * - period: duration of a period in seconds
+     * - expiration: the budget can't be used after this date
+     * Outputs:
+     * - a Budget object
+     */
+    pub fn new(
+        amount: Decimal,
+        period: i64,
+        expiration: Instant,
+    ) -> Budget {
+        Budget {
+            amount,
+            period,
+            expiration,
+            period_start: Clock::current_time_rounded_to_seconds(),
+            spent: Decimal::ZERO,
+        }
+    }
+
+    /* Register an expense and start a new period if the current one is over.
+     * The method panics if the budget is expired or the amount exceeds what is left in the
+     * current period.
+     * Input parameters:
+     * - amount: the amount to spend
+     * Outputs:
+     * - the amount left in the current period
+     */
+    pub fn consume(
+        &mut self,
+        amount: Decimal,
+    ) -> Decimal {
+        assert!(
+            Clock::current_time_is_strictly_before(self.expiration, TimePrecision::Second),
+            "Expired budget"
+        );
+        assert!(
+            amount > Decimal::ZERO,
+            "Amount must be positive"
+        );
+
+        // Skip the periods that are over
+        let now = Clock::current_time_rounded_to_seconds();
+        let elapsed_periods = (now.seconds_since_unix_epoch -
+            self.period_start.seconds_since_unix_epoch) / self.period;
+        if elapsed_periods > 0 {
+            self.period_start = self.period_start.add_seconds(elapsed_periods * self.period)
+                .unwrap();
+            self.spent = Decimal::ZERO;
+        }
+
+        assert!(
+            self.spent + amount <= self.amount,
+            "Budget exceeded"
+        );
+        self.spent += amount;
+
+        self.amount - self.spent
+    }
+
+    /* Register an expense of a member and emit a BudgetSpentEvent.
+     * The method panics if the budget is expired or the amount exceeds what is left in the
+     * current period.
+     * Input parameters:
+     * - badge_id: the non fungible id of the member badge spending the budget
+     * - resource: the resource address of the budget
+     * - amount: the amount to spend
+     * - recipient: the account receiving the amount
+     */
+    pub fn spend(
+        &mut self,
+        badge_id: NonFungibleLocalId,
+        resource: ResourceAddress,
+        amount: Decimal,
+        recipient: Global<Account>,
+    ) {
+        let remaining = self.consume(amount);
+
+        Runtime::emit_event(
+            BudgetSpentEvent {
+                badge_id,
+                resource,
+                amount,
+                recipient,
+                remaining,
+            }
+        );
+    }
+}
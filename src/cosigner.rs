@@ -12,6 +12,14 @@ pub struct Cosigner {
     // Non fungible id of the member badge of the signer
     badge_id: NonFungibleLocalId,
 
+    // Whether the signer is the recipient or the target of the operation; such a signature
+    // doesn't count toward the min_cosigners threshold
+    conflict_of_interest: bool,
+
+    // Non fungible id of the member badge that signed on behalf of this one through a
+    // delegation, or None if the member signed himself
+    delegate: Option<NonFungibleLocalId>,
+
     // The hash of the transaction used to sign the operation
     transaction_hash: scrypto::prelude::Hash,
     // TODO: how to obtain in the event a nice string representation such as
@@ -29,14 +37,20 @@ impl Cosigner {
     /* Create a Cosigner object relative to the specified member badge
      * Input parameters:
      * - badge_id: non fungible id of the member badge used to sign an operation
+     * - conflict_of_interest: whether the signer is the recipient or the target of the operation
+     * - delegate: id of the member badge signing on behalf of this one or None
      * Outputs:
      * - a Cosigner object
      */
     pub fn new(
         badge_id: NonFungibleLocalId,
+        conflict_of_interest: bool,
+        delegate: Option<NonFungibleLocalId>,
     ) -> Cosigner {
         Cosigner {
-            badge_id: badge_id,
+            badge_id,
+            conflict_of_interest,
+            delegate,
             transaction_hash: Runtime::transaction_hash(),
         }
     }
@@ -56,6 +70,49 @@ impl Cosigner {
         )
             .is_enabled()
     }
+
+    /* Check if this signature has been added by the specified member badge through a
+     * delegation.
+     * Input parameters:
+     * - delegate: non fungible id of a member badge
+     * Outputs:
+     * - true if the specified member badge signed on behalf of this one
+     */
+    pub fn is_delegated_to(
+        &self,
+        delegate: &NonFungibleLocalId,
+    ) -> bool {
+        self.delegate.as_ref() == Some(delegate)
+    }
+
+    /* Check if this signature has been flagged as a conflict of interest.
+     * Outputs:
+     * - true if the signer is the recipient or the target of the operation
+     */
+    pub fn is_conflict_of_interest(&self) -> bool {
+        self.conflict_of_interest
+    }
+
+    /* Check if this Cosigner corresponds to a member badge in its probation period; the
+     * signatures of these badges do not count toward the min_cosigners threshold.
+     * Input parameters:
+     * - resource_manager: the resource manager of the member badges
+     * - probation_period: number of seconds since the creation date the probation lasts
+     * - founding_members: number of member badges minted during the component instantiation
+     * Outputs:
+     * - true if the badge corresponding to this signature is in its probation period
+     */
+    pub fn is_on_probation(
+        &self,
+        resource_manager: &NonFungibleResourceManager,
+        probation_period: i64,
+        founding_members: u64,
+    ) -> bool {
+        resource_manager.get_non_fungible_data::<Member>(
+            &self.badge_id,
+        )
+            .is_on_probation(&self.badge_id, probation_period, founding_members)
+    }
 }
 
 impl PartialEq for Cosigner {
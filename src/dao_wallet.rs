@@ -2,6 +2,14 @@ use scrypto::prelude::*;
 use crate::cosigner::*;
 use crate::operation::*;
 use crate::member::*;
+use crate::application::*;
+use crate::budget::*;
+use crate::allowance::*;
+use crate::delegation::*;
+use crate::action::*;
+use crate::resource_action::*;
+use crate::new_resource::*;
+use crate::operation_details::*;
 
 /* A DaoWallet is a wrapper around an Account that implements M-of-N multisignature.
  * A single operation must be signed by min_cosigners members to be executed; each member executes
@@ -15,6 +23,14 @@ use crate::member::*;
 #[events(
     NewOperationEvent,
     OperationExecutedEvent,
+    MemberExpiredEvent,
+    NewApplicationEvent,
+    BondStoredEvent,
+    MemberOverdueEvent,
+    BudgetSpentEvent,
+    AllowanceWithdrawnEvent,
+    PaymentStoredEvent,
+    ResourceCreatedEvent,
 )]
 mod dao_wallet {
     struct DaoWallet {
@@ -36,16 +52,130 @@ mod dao_wallet {
 
         // The numeric non fungible id of the next member badge to mint
         next_badge_id: u64,
+
+        // Number of seconds after its creation a new member badge can't count as a cosigner
+        probation_period: i64,
+
+        // Number of member badges minted at instantiation, they are not subject to probation
+        founding_members: u64,
+
+        // Duration in seconds of the term of the member badges minted after instantiation or None
+        // if they never expire
+        member_term: Option<i64>,
+
+        // Pending invitations: the accounts that can claim a member badge and the expiration
+        // of each invitation, or None for the founding members who refused their badge
+        invitations: KeyValueStore<Global<Account>, Option<Instant>>,
+
+        // Pending membership applications
+        applications: KeyValueStore<Global<Account>, Application>,
+
+        // The vault containing the XRD bonds of the pending applications
+        application_bonds: Vault,
+
+        // The minimum XRD bond an outsider must lock to apply for membership
+        application_bond: Decimal,
+
+        // Number of seconds a membership application stays valid
+        application_period: i64,
+
+        // Whether the bond of a rejected or expired application goes to the DAO treasury (true)
+        // or back to the candidate (false)
+        forfeit_bonds: bool,
+
+        // Membership dues: resource, amount and number of seconds the amount covers, or None
+        dues: Option<(ResourceAddress, Decimal, i64)>,
+
+        // Number of seconds the dues can stay unpaid before the member badge can be disabled
+        dues_grace_period: i64,
+
+        // Whether a signature by the recipient or the target of an operation is refused (true)
+        // or accepted without counting toward min_cosigners (false)
+        refuse_conflicts_of_interest: bool,
+
+        // Whether a recipient refusing a payment of a SendFungibles, SendNonFungibles or
+        // SendFungiblesMulti operation makes the whole operation fail (true) or the payment is
+        // stored in the AccountLocker (false); refused bond refunds are always stored in the
+        // AccountLocker, any other refused deposit (member badges, allowance badges, minted
+        // resources, budget expenses) always fails
+        abort_refused_deposits: bool,
+
+        // Budgets members can spend without the signatures of the other members, by member
+        // badge and resource
+        budgets: KeyValueStore<(NonFungibleLocalId, ResourceAddress), Budget>,
+
+        // ResourceManager to mint allowance badges
+        allowance_badges_resource_manager: NonFungibleResourceManager,
+
+        // The numeric non fungible id of the next allowance badge to mint
+        next_allowance_id: u64,
+
+        // Allowances that have not been revoked, by allowance badge id
+        allowances: KeyValueStore<NonFungibleLocalId, Allowance>,
+
+        // The vault containing the member badges of the keyless members
+        keyless_member_badges: NonFungibleVault,
+
+        // The member badge id of each keyless member, by virtual signature badge
+        keyless_signers: KeyValueStore<NonFungibleGlobalId, NonFungibleLocalId>,
+
+        // The BLS public keys the members use to sign operations off-ledger, by member badge id
+        bls_keys: KeyValueStore<NonFungibleLocalId, Bls12381G1PublicKey>,
+
+        // The operations by hash and their nonce; the nonce of an operation is increased each time
+        // it is executed or a signature is removed from it
+        operation_hashes: KeyValueStore<Hash, (Operation, u64)>,
+
+        // The AccountLocker holding the payments refused by their recipients until they claim
+        // them
+        account_locker: Global<AccountLocker>,
+
+        // The badge this component presents on every deposit it makes, so that accounts can
+        // add it to their authorized depositors
+        depositor_badge: FungibleVault,
+
+        // The resources created by CreateResource operations, the only ones that can be managed
+        // without a badge
+        created_resources: KeyValueStore<ResourceAddress, ()>,
     }
 
     impl DaoWallet {
 
         /* Instantiate a DaoWallet component, mint member badges, send them to the member
          * accounts, set the number of signatures needed for a transaction to be executed, create
-         * an account to hold the DAO treasury and a badge to manage it.
+         * an account to hold the DAO treasury and a badge to manage it, an AccountLocker to
+         * store the payments that recipients refuse and the badge the component presents when
+         * depositing.
          * Input parameters:
-         * - members: list of the accounts that will receive the member badges
+         * - members: list of the accounts that will receive the member badges; an account
+         *   refusing the deposit gets an invitation that never expires instead
          * - min_cosigners: the number of different member badges needed to sign an operation
+         * - name: name of the member badges
+         * - symbol: symbol of the member badges
+         * - badge_description: description of the member badges
+         * - icon_url: URL of the icon of the member badges
+         * - tags: list of tags of the member badges
+         * - dapp_definition: address of the dApp definition account of this DAO or None
+         * - probation_period: number of seconds after its creation a new member badge can
+         *   propose operations but its signature doesn't count toward min_cosigners
+         * - member_term: duration in seconds of the term of the member badges minted after
+         *   instantiation or None if they never expire; the badges of the founding members never
+         *   expire unless they are renewed, so that the DAO can't lock itself out
+         * - application_bond: minimum amount of XRD an outsider must lock to apply for
+         *   membership
+         * - application_period: number of seconds a membership application stays valid
+         * - forfeit_bonds: whether the bonds of rejected or expired applications go to the DAO
+         *   treasury instead of back to the candidates
+         * - dues: resource, amount and period in seconds of the membership dues or None if
+         *   members don't pay dues
+         * - dues_grace_period: number of seconds the dues can stay unpaid before the member
+         *   badge can be disabled
+         * - refuse_conflicts_of_interest: whether the signature of a member who is the recipient
+         *   or the target of an operation is refused or just doesn't count toward min_cosigners
+         * - abort_refused_deposits: whether a recipient refusing a payment of a SendFungibles,
+         *   SendNonFungibles or SendFungiblesMulti operation makes the whole operation fail or
+         *   the payment is stored in the AccountLocker; refused bond refunds are always stored,
+         *   any other refused deposit always fails
          * Outputs:
          * - the DaoWallet component
          * - the resource address of the account badges
@@ -54,6 +184,21 @@ mod dao_wallet {
         pub fn new(
             mut members: IndexSet<Global<Account>>,
             min_cosigners: usize,
+            name: String,
+            symbol: String,
+            badge_description: String,
+            icon_url: String,
+            tags: Vec<String>,
+            dapp_definition: Option<GlobalAddress>,
+            probation_period: i64,
+            member_term: Option<i64>,
+            application_bond: Decimal,
+            application_period: i64,
+            forfeit_bonds: bool,
+            dues: Option<(ResourceAddress, Decimal, i64)>,
+            dues_grace_period: i64,
+            refuse_conflicts_of_interest: bool,
+            abort_refused_deposits: bool,
         ) -> (
             Global<DaoWallet>,
             ResourceAddress,
@@ -69,39 +214,72 @@ mod dao_wallet {
                 min_cosigners <= members.len(),
                 "Not enough members"
             );
+            assert!(
+                probation_period >= 0,
+                "Negative probation period"
+            );
+            assert!(
+                member_term.unwrap_or(1) > 0,
+                "Member term must be positive"
+            );
+            assert!(
+                application_bond >= Decimal::ZERO,
+                "Negative application bond"
+            );
+            assert!(
+                application_period > 0,
+                "Application period must be positive"
+            );
+            if let Some((_, dues_amount, dues_period)) = dues {
+                assert!(
+                    dues_amount > Decimal::ZERO && dues_period > 0,
+                    "Dues amount and period must be positive"
+                );
+            }
+            assert!(
+                dues_grace_period >= 0,
+                "Negative dues grace period"
+            );
 
             // Reserve a component address to set permissions
             let (address_reservation, component_address) =
                 Runtime::allocate_component_address(DaoWallet::blueprint_id());
 
-            // Prepare the list of member badges to create
-            let mut member_badges_specification = vec![];
-            for n in 1..=members.len() {
-                member_badges_specification.push(
-                    (
-                        IntegerNonFungibleLocalId::from(n as u64),
-                        Member::new()
-                    )
+            // Prepare the metadata of the member badges, only this component will be able to
+            // update them
+            let mut member_badges_metadata = metadata!(
+                roles {
+                    metadata_setter => rule!(require(global_caller(component_address)));
+                    metadata_setter_updater => rule!(deny_all);
+                    metadata_locker => rule!(deny_all);
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name" => name, updatable;
+                    "symbol" => symbol, updatable;
+                    "description" => badge_description, updatable;
+                    "icon_url" => UncheckedUrl::of(icon_url), updatable;
+                    "tags" => tags, updatable;
+                }
+            );
+            if let Some(dapp_definition) = dapp_definition {
+                member_badges_metadata.init.set_metadata(
+                    "dapp_definitions",
+                    vec![dapp_definition],
                 );
             }
 
-            // Create member badges
-            let mut member_badges_bucket = ResourceBuilder::new_integer_non_fungible::<Member>(
+            // Create the member badges resource
+            let member_badges_resource_manager = ResourceBuilder::new_integer_non_fungible::<Member>(
                 OwnerRole::None
             )
-                .metadata(metadata!(
-                    roles {
-                        metadata_setter => rule!(deny_all);
-                        metadata_setter_updater => rule!(deny_all);
-                        metadata_locker => rule!(deny_all);
-                        metadata_locker_updater => rule!(deny_all);
-                    },
-                    init {
-                        "name" => "DAO wallet badge", locked;
-                    }
-                ))
+                .metadata(member_badges_metadata)
                 .mint_roles(mint_roles!(
-                    minter => rule!(require(global_caller(component_address)));
+                    // This function mints the founding member badges
+                    minter => rule!(
+                        require(global_caller(component_address)) ||
+                        require(global_caller(DaoWallet::blueprint_id()))
+                    );
                     minter_updater => rule!(deny_all);
                 ))
                 .burn_roles(burn_roles!(
@@ -117,43 +295,125 @@ mod dao_wallet {
                     non_fungible_data_updater => rule!(require(global_caller(component_address)));
                     non_fungible_data_updater_updater => rule!(deny_all);
                 ))
-                .mint_initial_supply(member_badges_specification);
+                .create_with_no_initial_supply();
+            let member_badges_address = member_badges_resource_manager.address();
 
-            // Get the ResourceAddressand the ResourceManager of the member badges
-            let member_badges_address = member_badges_bucket.resource_address();
-            let member_badges_resource_manager =
-                NonFungibleResourceManager::from(member_badges_address);
+            // Create the resource for the allowance badges; they are transferable so that the
+            // holder can pass them to a component
+            let allowance_badges_resource_manager =
+                ResourceBuilder::new_integer_non_fungible::<AllowanceBadge>(OwnerRole::None)
+                .metadata(metadata!(
+                    init {
+                        "name" => "Allowance badge", locked;
+                        "description" => "Allows to withdraw from the DAO treasury within limits", locked;
+                    }
+                ))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .burn_roles(burn_roles!(
+                    burner => rule!(allow_all);
+                    burner_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply();
 
-            // Get ready to mint more member badges
-            let next_badge_id = members.len() as u64 + 1;
+            // Create the badge this component presents when depositing in an account
+            let depositor_badge_bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata!(
+                    init {
+                        "name" => "DAO wallet depositor badge", locked;
+                        "description" => "Add this badge to the authorized depositors of your account to receive the DAO payments", locked;
+                    }
+                ))
+                .mint_initial_supply(1);
+            let depositor_badge = ResourceOrNonFungible::Resource(
+                depositor_badge_bucket.resource_address()
+            );
 
-            // Send badges to the members accounts
-            let mut id: u64 = 1;
+            // Send badges to the members accounts starting from the last account in the list;
+            // the accounts that refuse them get an invitation that never expires instead, so
+            // that ids are not wasted. Founding member badges have no term
+            let invitations = KeyValueStore::new();
+            let mut founding_members: u64 = 0;
             while let Some(mut member) = members.pop() {
-                member.try_deposit_or_abort(
-                    member_badges_bucket.take_non_fungible(
-                        &NonFungibleLocalId::Integer(id.into())
-                    ).into(),
-                    None
+
+                // Try an empty bucket first to know whether the account accepts member badges
+                let refused_bucket = depositor_badge_bucket.authorize_with_all(
+                    || member.try_deposit_or_refund(
+                        member_badges_resource_manager.create_empty_bucket().into(),
+                        Some(depositor_badge.clone())
+                    )
                 );
+                if let Some(refused_bucket) = refused_bucket {
+                    refused_bucket.drop_empty();
+                    invitations.insert(member, None);
+                    continue;
+                }
 
-                id += 1;
+                founding_members += 1;
+                let member_badge_bucket = member_badges_resource_manager.mint_non_fungible(
+                    &NonFungibleLocalId::Integer(founding_members.into()),
+                    Member::new(None, member)
+                );
+                depositor_badge_bucket.authorize_with_all(
+                    || member.try_deposit_or_abort(
+                        member_badge_bucket.into(),
+                        Some(depositor_badge.clone())
+                    )
+                );
             }
 
-            // Burn the empty bucket
-            member_badges_bucket.burn();
+            // Get ready to mint more member badges
+            let next_badge_id = founding_members + 1;
 
             // Create an Account to hold the DAO treasury and a badge to manage it
             let (account, account_badge) = Blueprint::<Account>::create();
 
+            // Create an AccountLocker to store the payments recipients refuse; only this
+            // component can store in it, nobody can recover what has been stored
+            let account_locker = Blueprint::<AccountLocker>::instantiate(
+                OwnerRole::None,
+                rule!(require(global_caller(component_address))),
+                rule!(deny_all),
+                rule!(deny_all),
+                rule!(deny_all),
+                None,
+            );
+
             // Instantiate the DaoWallet component
             let dao_wallet = Self {
-                member_badges_resource_manager: member_badges_resource_manager,
-                min_cosigners: min_cosigners,
-                account: account,
+                member_badges_resource_manager,
+                min_cosigners,
+                account,
                 account_badge: NonFungibleVault::with_bucket(account_badge),
                 operations: KeyValueStore::new_with_registered_type(),
-                next_badge_id: next_badge_id,
+                next_badge_id,
+                probation_period,
+                founding_members,
+                member_term,
+                invitations,
+                applications: KeyValueStore::new(),
+                application_bonds: Vault::new(XRD),
+                application_bond,
+                application_period,
+                forfeit_bonds,
+                dues,
+                dues_grace_period,
+                refuse_conflicts_of_interest,
+                abort_refused_deposits,
+                budgets: KeyValueStore::new(),
+                allowance_badges_resource_manager,
+                next_allowance_id: 1,
+                allowances: KeyValueStore::new(),
+                keyless_member_badges: NonFungibleVault::new(member_badges_address),
+                keyless_signers: KeyValueStore::new(),
+                bls_keys: KeyValueStore::new(),
+                operation_hashes: KeyValueStore::new(),
+                account_locker,
+                depositor_badge: FungibleVault::with_bucket(depositor_badge_bucket),
+                created_resources: KeyValueStore::new(),
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::None)
@@ -166,6 +426,8 @@ mod dao_wallet {
 
         /* Private method to validate a member badge proof and get the numeric id of the used
          * member badge.
+         * A member badge in its probation period is accepted, add_cosigner will not count it
+         * toward the min_cosigners threshold.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
          * Outputs:
@@ -184,7 +446,11 @@ mod dao_wallet {
                 .as_non_fungible()
                 .non_fungible::<Member>();
 
-            // Verify that the member badge is enabled
+            // Verify that the member badge is enabled and its term is not over
+            assert!(
+                !non_fungible.data().is_expired(),
+                "Expired badge"
+            );
             assert!(
                 non_fungible.data().is_enabled(),
                 "Disabled badge"
@@ -194,267 +460,3022 @@ mod dao_wallet {
             non_fungible.local_id().clone()
         }
 
-        /* Private method to add a cosigner to the list of those who signed an operation.
-         * Returns true if min_cosigners has been reached (the operation can be executed).
+        /* Private method to validate a list of proofs of member badges and get the numeric ids
+         * of the distinct member badges they contain.
          * Input parameters:
-         * - operation: the operation to sign
-         * - badge_id: the non fungible id of the member badge signing the operation
+         * - member_badge_proofs: proofs of ownership of one or more member badges each
          * Outputs:
-         * - true if enough signatures exist, false otherwise
+         * - the non fungible ids of the member badges used to create the proofs
          */
-        fn add_cosigner(
-            &mut self,
-            operation: Operation,
-            badge_id: NonFungibleLocalId,
-        ) -> bool {
-
-            // Create the cosigner
-            let cosigner = Cosigner::new(badge_id.clone());
-
-            // Check if the operation already exists
-            let mut opt_cosigners = self.operations.get_mut(&operation);
-            match opt_cosigners {
-
-                // If not
-                None => {
-
-                    // Emit the NewOperationEvent
-                    operation.emit_new_event(badge_id);
-
-                    // Avoid multiple borrows error
-                    drop(opt_cosigners);
+        fn get_badge_ids(
+            &self,
+            member_badge_proofs: Vec<Proof>,
+        ) -> Vec<NonFungibleLocalId> {
+            let mut badge_ids: Vec<NonFungibleLocalId> = vec![];
 
-                    // Add the new operation, with a single signer, to the list
-                    self.operations.insert(
-                        operation,
-                        indexset!(cosigner),
-                    );
-                },
+            for member_badge_proof in member_badge_proofs.into_iter() {
 
-                // If yes
-                Some(ref mut cosigners) => {
+                // Verify the ResourceAddress
+                let non_fungibles = member_badge_proof.check_with_message(
+                    self.member_badges_resource_manager.address(),
+                    "Incorrect proof",
+                )
+                    .as_non_fungible()
+                    .non_fungibles::<Member>();
 
-                    // Remove eventual cosigners whose badge has been disabled in the meantime
-                    cosigners.retain(
-                        |cosigner: &Cosigner| {
-                            cosigner.is_enabled(&self.member_badges_resource_manager)
-                        }
+                // Verify that each member badge is enabled and its term is not over
+                for non_fungible in non_fungibles.iter() {
+                    assert!(
+                        !non_fungible.data().is_expired(),
+                        "Expired badge"
                     );
-
-                    // Add the new cosigner to the list, fail if it was already there
                     assert!(
-                        cosigners.insert(cosigner),
-                        "You already signed this operation"
+                        non_fungible.data().is_enabled(),
+                        "Disabled badge"
                     );
-            
-                    // If the operation has enough cosigners emit the OperationExecutedEvent,
-                    // reset the list of cosigners and return true
-                    if cosigners.len() >= self.min_cosigners {
-                        operation.emit_executed_event(cosigners.clone());
-                        cosigners.clear();
-                        return true;
+
+                    if !badge_ids.contains(non_fungible.local_id()) {
+                        badge_ids.push(non_fungible.local_id().clone());
                     }
-                },
+                }
             }
 
-            // Return false
-            false
+            assert!(
+                !badge_ids.is_empty(),
+                "No member badge"
+            );
+
+            badge_ids
         }
 
-        /* Internal method to verify that it is possible to withdraw the specified resource from
-         * the account.
-         * There's no output, the method panics if the operation is impossible.
+        /* Private method to verify that the specified member badges are in the auth zone of the
+         * transaction; the component can't list the proofs in the auth zone, so the caller has to
+         * tell which badges it contains.
          * Input parameters:
-         * - resource: the address of the resource to withdraw
-         * - amount: the amount to withdraw if resource is fungible, None otherways
-         * - non_fungible_ids: the list of non fungibles to withdraw or None
+         * - badge_ids: the non fungible ids of the member badges in the auth zone
+         * Outputs:
+         * - the distinct non fungible ids of the member badges
          */
-        fn check_availability(
+        fn get_badge_ids_from_auth_zone(
             &self,
-            resource: ResourceAddress,
-            amount: Option<Decimal>,
-            non_fungible_ids: Option<&Vec<NonFungibleLocalId>>,
-        ) {
+            badge_ids: Vec<NonFungibleLocalId>,
+        ) -> Vec<NonFungibleLocalId> {
+            let mut distinct_badge_ids: Vec<NonFungibleLocalId> = vec![];
+
+            for badge_id in badge_ids.into_iter() {
+
+                let member = self.assert_badge_in_auth_zone(&badge_id);
+
+                // Verify that the member badge is enabled and its term is not over
+                assert!(
+                    !member.is_expired(),
+                    "Expired badge"
+                );
+                assert!(
+                    member.is_enabled(),
+                    "Disabled badge"
+                );
+
+                if !distinct_badge_ids.contains(&badge_id) {
+                    distinct_badge_ids.push(badge_id);
+                }
+            }
 
-            // The account badge is needed in order to withdraw anything
             assert!(
-                !self.account_badge.is_empty(),
-                "The account badge is missing"
+                !distinct_badge_ids.is_empty(),
+                "No member badge"
             );
 
-            // Fungibles/non fungible specific checks
-            match resource.is_fungible() {
-                true => {
-                    let amount = amount.expect("No amount specified");
+            distinct_badge_ids
+        }
 
-                    assert!(
-                        amount > Decimal::ZERO,
-                        "Amount must be positive"
-                    );
-                    assert!(
-                        self.account.balance(resource) >= amount,
-                        "Not enough funds!"
-                    );
-                    assert!(
-                        non_fungible_ids.is_none(),
-                        "Fungible/non fungible mismatch"
-                    );
-                },
+        /* Private method to verify that a proof of a member badge is in the auth zone of the
+         * transaction, or that the transaction is signed with the key of a keyless member; the
+         * member badge may be disabled or expired.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * Outputs:
+         * - the non fungible data of the member badge
+         */
+        fn assert_badge_in_auth_zone(
+            &self,
+            badge_id: &NonFungibleLocalId,
+        ) -> Member {
+            let member = self.member_badges_resource_manager
+                .get_non_fungible_data::<Member>(badge_id);
 
-                false => {
-                    let non_fungible_ids = non_fungible_ids.expect("No ids specified");
+            // For a keyless member the transaction must be signed with his key instead
+            let signer = member.signer().unwrap_or(
+                NonFungibleGlobalId::new(
+                    self.member_badges_resource_manager.address(),
+                    badge_id.clone(),
+                )
+            );
+            Runtime::assert_access_rule(rule!(require(signer)));
 
-                    assert!(
-                        non_fungible_ids.len() > 0,
-                        "I'm not sending zero NFTs"
-                    );
-                    for non_fungible_id in non_fungible_ids.iter() {
-                        assert!(
-                            self.account.has_non_fungible(
-                                resource,
-                                non_fungible_id.clone()
-                            ),
-                            "We don't have that NFT"
-                        );
-                    }
-                    assert!(
-                        amount.is_none(),
-                        "Fungible/non fungible mismatch"
-                    );
-                }
-            }
+            member
         }
 
-        /* A member can invoke this method to create/sign an operation to mint a new member badge
-         * and send it to the specified account.
+        /* Private method to add one or more cosigners to the list of those who signed an
+         * operation and execute it if min_cosigners has been reached.
+         * The members who delegated their signing power to the cosigners are added too.
          * Input parameters:
-         * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - recipient: the account address to send the new member badge to
+         * - operation: the operation to sign
+         * - badge_ids: the non fungible ids of the member badges signing the operation
+         * Outputs:
+         * - the hash of the operation
          */
-        pub fn mint_member_badge(
+        fn add_cosigners(
             &mut self,
-            member_badge_proof: Proof,
-            description: String,
-            mut recipient: Global<Account>,
-        ) {
+            operation: Operation,
+            badge_ids: Vec<NonFungibleLocalId>,
+        ) -> Hash {
 
-            // Get the id of the member badge
-            let badge_id = self.get_badge_id(member_badge_proof);
+            // Verify that the operation makes sense and is possible
+            self.check_operation(&operation, &badge_ids);
 
-            // Create the operation
-            let operation = Operation::new(
-                description.clone(),
-                OperationType::MintBadge,
-                None,
-                None,
-                None,
-                Some(recipient),
-                None,
-                None,
+            // Get the list of the cosigners of the operation, if it doesn't exist yet emit the
+            // NewOperationEvent, register its hash and start a new list
+            let operation_hash = operation.hash();
+            let mut cosigners = match self.operations.get(&operation) {
+                None => {
+                    operation.emit_new_event(badge_ids[0].clone());
+
+                    self.operation_hashes.insert(operation_hash, (operation.clone(), 0));
+
+                    indexset!()
+                },
+                Some(cosigners) => cosigners.clone(),
+            };
+
+            // Remove eventual cosigners whose badge has been disabled in the meantime
+            cosigners.retain(
+                |cosigner: &Cosigner| {
+                    cosigner.is_enabled(&self.member_badges_resource_manager)
+                }
             );
 
-            // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+            for badge_id in badge_ids.iter() {
 
-                // If enough members signed the operation, create a new member badge
-                let member_badge_bucket = self.member_badges_resource_manager.mint_non_fungible(
-                    &NonFungibleLocalId::Integer(self.next_badge_id.into()),
-                    Member::new()
-                );
+                // Record the activity of the member
+                Member::update_last_active(badge_id, &self.member_badges_resource_manager);
 
-                // Send the new member badge to the specified account
-                recipient.try_deposit_or_abort(
-                    member_badge_bucket.into(),
-                    None
+                // Check whether the member is the recipient or the target of the operation
+                let conflict_of_interest = operation.is_conflict_of_interest(
+                    badge_id,
+                    self.member_badges_resource_manager.get_non_fungible_data::<Member>(badge_id)
+                        .accounts(),
+                );
+                assert!(
+                    !(conflict_of_interest && self.refuse_conflicts_of_interest),
+                    "Conflict of interest"
                 );
 
-                // Get ready for minting the next member badge
-                self.next_badge_id += 1;
+                // Add the new cosigner to the list, fail if it was already there
+                assert!(
+                    cosigners.insert(Cosigner::new(badge_id.clone(), conflict_of_interest, None)),
+                    "You already signed this operation"
+                );
             }
-        }
-
-        /* A member can invoke this method to create/sign an operation to disable a member badge.
-         * Input parameters:
-         * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - non_fungible_id: the id of the member badge to disable
-         */
-        pub fn disable_member_badge(
-            &mut self,
-            member_badge_proof: Proof,
-            description: String,
-            non_fungible_id: NonFungibleLocalId,
-        ) {
 
-            // Make sure that the specified member badge is currently enabled
-            assert!(
-                Member::is_badge_enabled(
-                    &non_fungible_id,
+            // Also sign on behalf of the members who delegated their signing power to the
+            // cosigners, unless they already signed or this is a refused conflict of interest for
+            // them
+            for badge_id in badge_ids.iter() {
+                for delegator in Member::delegators(
+                    badge_id,
+                    operation.operation_type(),
                     &self.member_badges_resource_manager,
-                ),
-                "Member badge already disabled"
-            );
+                ) {
+                    let conflict_of_interest = operation.is_conflict_of_interest(
+                        &delegator,
+                        self.member_badges_resource_manager
+                            .get_non_fungible_data::<Member>(&delegator)
+                            .accounts(),
+                    );
+                    if !(conflict_of_interest && self.refuse_conflicts_of_interest) {
 
-            // Make sure that a sufficient number of member badges will be enabled after the
-            // operation
-            let enabled_members = Member::count_enabled_members(&self.member_badges_resource_manager);
-            assert!(
-                enabled_members > self.min_cosigners,
-                "Not enough enabled members remaining"
-            );
+                        // Signing through a delegate is an activity of the delegator too
+                        Member::update_last_active(
+                            &delegator,
+                            &self.member_badges_resource_manager,
+                        );
 
-            // Get the id of the signing member badge
-            let badge_id = self.get_badge_id(member_badge_proof);
+                        cosigners.insert(
+                            Cosigner::new(delegator, conflict_of_interest, Some(badge_id.clone()))
+                        );
+                    }
+                }
+            }
 
-            // Create the operation
-            let operation = Operation::new(
-                description.clone(),
-                OperationType::DisableBadge,
-                None,
-                None,
-                Some(vec![non_fungible_id.clone()]),
-                None,
-                None,
-                None,
-            );
+            // Member badges in their probation period and signatures flagged as conflict of
+            // interest don't count toward the threshold
+            let counting_cosigners = cosigners.iter()
+                .filter(
+                    |cosigner: &&Cosigner| {
+                        !cosigner.is_conflict_of_interest() &&
+                        !cosigner.is_on_probation(
+                            &self.member_badges_resource_manager,
+                            self.probation_period,
+                            self.founding_members,
+                        )
+                    }
+                )
+                .count();
 
-            // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+            // If the operation has enough cosigners take the list of cosigners, leaving it empty
+            // for the next execution, and increase the nonce of the operation
+            let execute = counting_cosigners >= self.min_cosigners;
+            let mut executed_cosigners = indexset!();
+            if execute {
+                executed_cosigners = std::mem::take(&mut cosigners);
+                self.operation_hashes.get_mut(&operation_hash).unwrap().1 += 1;
+            }
 
-                // If enough members signed the operation, disable the specified member badge
-                Member::enable(
-                    &non_fungible_id,
-                    false,
-                    &self.member_badges_resource_manager,
-                );
+            // Save the updated list of cosigners
+            self.operations.insert(
+                operation.clone(),
+                cosigners,
+            );
+
+            // Execute the operation and emit the OperationExecutedEvent, including the resources
+            // the execution deposited in the DAO treasury
+            if execute {
+                let received = self.execute_operation(&operation);
+                operation.emit_executed_event(executed_cosigners, received);
             }
+
+            operation_hash
         }
 
-        /* A member can invoke this method to create/sign an operation to enable a member badge
-         * that has been previously disabled.
+        /* Private method to verify that an operation makes sense and is possible before a member
+         * signs it.
+         * There's no output, the method panics if the operation is not acceptable.
          * Input parameters:
-         * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - non_fungible_id: the id of the member badge to enable
+         * - operation: the operation to verify
+         * - badge_ids: the non fungible ids of the member badges signing the operation
          */
-        pub fn enable_member_badge(
-            &mut self,
-            member_badge_proof: Proof,
-            description: String,
-            non_fungible_id: NonFungibleLocalId,
+        fn check_operation(
+            &self,
+            operation: &Operation,
+            badge_ids: &[NonFungibleLocalId],
         ) {
+            match operation.operation_type() {
 
-            // Make sure that the specified member badge is currently disabled
-            assert!(
-                !Member::is_badge_enabled(
-                    &non_fungible_id,
-                    &self.member_badges_resource_manager,
+                OperationType::MintBadge
+                | OperationType::SetMemberBadgeMetadata => {},
+
+                OperationType::DisableBadge => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Make sure that the specified member badge is currently enabled
+                    assert!(
+                        Member::is_badge_enabled(
+                            &non_fungible_id,
+                            &self.member_badges_resource_manager,
+                        ),
+                        "Member badge already disabled"
+                    );
+
+                    // Make sure that a sufficient number of member badges will be enabled after
+                    // the operation
+                    let enabled_members = Member::count_enabled_members(
+                        &self.member_badges_resource_manager,
+                        self.probation_period,
+                        self.founding_members,
+                    );
+                    assert!(
+                        enabled_members > self.min_cosigners,
+                        "Not enough enabled members remaining"
+                    );
+                },
+
+                OperationType::EnableBadge => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Make sure that the specified member badge is currently disabled
+                    assert!(
+                        !Member::is_badge_enabled(
+                            &non_fungible_id,
+                            &self.member_badges_resource_manager,
+                        ),
+                        "Member badge already enabled"
+                    );
+                },
+
+                OperationType::IncreaseMinCosigners => {
+
+                    // Make sure that enough members exist to sign future operatons after the
+                    // threshold increase
+                    let enabled_members = Member::count_enabled_members(
+                        &self.member_badges_resource_manager,
+                        self.probation_period,
+                        self.founding_members,
+                    );
+                    assert!(
+                        enabled_members > self.min_cosigners,
+                        "Not enough enabled members"
+                    );
+                },
+
+                OperationType::DecreaseMinCosigners => {
+
+                    // Do not allow the number of cosigners be 1 or less
+                    assert!(
+                        self.min_cosigners > 2,
+                        "Remaining cosigners must be more than 1"
+                    );
+                },
+
+                OperationType::SendFungibles => {
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        operation.resource().unwrap(),
+                        operation.amount(),
+                        None,
+                    );
+                },
+
+                OperationType::SendFungiblesMulti => {
+                    let payments = operation.payments().unwrap();
+
+                    // Verify that the operation is possible
+                    assert!(
+                        !payments.is_empty(),
+                        "No payments"
+                    );
+                    let mut total = Decimal::ZERO;
+                    for (_, amount) in payments.iter() {
+                        assert!(
+                            *amount > Decimal::ZERO,
+                            "Amount must be positive"
+                        );
+                        total += *amount;
+                    }
+                    self.check_availability(
+                        operation.resource().unwrap(),
+                        Some(total),
+                        None,
+                    );
+                },
+
+                OperationType::SendNonFungibles => {
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        operation.resource().unwrap(),
+                        None,
+                        Some(&operation.non_fungible_ids().unwrap()),
+                    );
+                },
+
+                OperationType::TransferAccountBadge => {
+
+                    // Verify that the operation is possible
+                    assert!(
+                        !self.account_badge.is_empty(),
+                        "The account badge is already gone"
+                    );
+                },
+
+                OperationType::Stake => {
+                    let mut validator = operation.validator().unwrap();
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        XRD,
+                        operation.amount(),
+                        None,
+                    );
+                    assert!(
+                        validator.accepts_delegated_stake(),
+                        "This validator doesn't accept user stake"
+                    );
+                },
+
+                OperationType::Unstake => {
+                    let validator = operation.validator().unwrap();
+
+                    // Find the lsu resource address for the specified validator
+                    let lsu_global_address: GlobalAddress = validator.get_metadata("pool_unit").ok().unwrap().unwrap();
+                    let lsu_address = ResourceAddress::try_from(lsu_global_address).unwrap();
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        lsu_address,
+                        operation.amount(),
+                        None,
+                    );
+                },
+
+                OperationType::ClaimUnstakedXrd => {
+                    let validator = operation.validator().unwrap();
+
+                    // Find the claim NFT resource address for the specified validator
+                    let claim_nft_global_address: GlobalAddress = validator.get_metadata("claim_nft").ok().unwrap().unwrap();
+                    let claim_nft_address = ResourceAddress::try_from(claim_nft_global_address).unwrap();
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        claim_nft_address,
+                        None,
+                        Some(&operation.non_fungible_ids().unwrap()),
+                    );
+                    //TODO: check claim epoch?
+                },
+
+                OperationType::RenewMember => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Make sure that the member badge exists and the new term is in the future
+                    assert!(
+                        self.member_badges_resource_manager.non_fungible_exists(&non_fungible_id),
+                        "Member badge not found"
+                    );
+                    assert!(
+                        Clock::current_time_is_strictly_before(
+                            operation.date().unwrap(),
+                            TimePrecision::Second,
+                        ),
+                        "The new term is already over"
+                    );
+                },
+
+                OperationType::DisableInactiveMembers => {
+                    let inactivity_period = operation.period().unwrap();
+
+                    assert!(
+                        inactivity_period > 0,
+                        "Inactivity period must be positive"
+                    );
+
+                    // Make sure that all of the listed member badges are still inactive; the
+                    // signers are active from now on
+                    let inactive_members = operation.non_fungible_ids().unwrap();
+                    let mut unique_ids: IndexSet<NonFungibleLocalId> = IndexSet::new();
+                    for non_fungible_id in inactive_members.iter() {
+                        assert!(
+                            unique_ids.insert(non_fungible_id.clone()),
+                            "Duplicate member badge"
+                        );
+                        assert!(
+                            !badge_ids.contains(non_fungible_id),
+                            "A signer is not inactive"
+                        );
+                        assert!(
+                            self.member_badges_resource_manager.non_fungible_exists(
+                                non_fungible_id
+                            ) &&
+                            self.member_badges_resource_manager
+                                .get_non_fungible_data::<Member>(non_fungible_id)
+                                .is_inactive(inactivity_period),
+                            "Member badge not inactive"
+                        );
+                    }
+
+                    // Make sure that a sufficient number of member badges will be enabled after
+                    // the operation
+                    self.check_inactive_members(inactive_members.len());
+                },
+
+                OperationType::InviteMember => {
+
+                    // Make sure that the invitation will not be already expired
+                    assert!(
+                        Clock::current_time_is_strictly_before(
+                            operation.date().unwrap(),
+                            TimePrecision::Second,
+                        ),
+                        "The invitation is already expired"
+                    );
+                },
+
+                OperationType::RevokeInvitation => {
+
+                    // Make sure that the invitation exists
+                    assert!(
+                        self.invitations.get(&operation.recipient().unwrap()).is_some(),
+                        "Invitation not found"
+                    );
+                },
+
+                OperationType::ApproveApplication => {
+
+                    // Make sure that the application can be approved
+                    self.check_application(operation.recipient().unwrap());
+                },
+
+                OperationType::RejectApplication => {
+
+                    // Make sure that the application exists
+                    assert!(
+                        self.applications.get(&operation.recipient().unwrap()).is_some(),
+                        "Application not found"
+                    );
+                },
+
+                OperationType::GrantBudget => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Verify that the operation makes sense
+                    assert!(
+                        self.member_badges_resource_manager.non_fungible_exists(&non_fungible_id),
+                        "Member badge not found"
+                    );
+                    assert!(
+                        operation.resource().unwrap().is_fungible(),
+                        "Budgets must be fungible"
+                    );
+                    assert!(
+                        operation.amount().unwrap() >= Decimal::ZERO,
+                        "Negative amount"
+                    );
+                    assert!(
+                        operation.period().unwrap() > 0,
+                        "Period must be positive"
+                    );
+                    assert!(
+                        Clock::current_time_is_strictly_before(
+                            operation.date().unwrap(),
+                            TimePrecision::Second,
+                        ),
+                        "The budget is already expired"
+                    );
+                },
+
+                OperationType::GrantAllowance => {
+
+                    // Verify that the operation makes sense
+                    assert!(
+                        operation.resource().unwrap().is_fungible(),
+                        "Allowances must be fungible"
+                    );
+                    assert!(
+                        operation.amount().unwrap() > Decimal::ZERO,
+                        "Amount must be positive"
+                    );
+                    assert!(
+                        operation.period().unwrap() > 0,
+                        "Period must be positive"
+                    );
+                    assert!(
+                        Clock::current_time_is_strictly_before(
+                            operation.date().unwrap(),
+                            TimePrecision::Second,
+                        ),
+                        "The allowance is already expired"
+                    );
+                },
+
+                OperationType::RevokeAllowance => {
+
+                    // Verify that the operation makes sense
+                    assert!(
+                        self.allowances.get(&operation.non_fungible_ids().unwrap()[0]).is_some(),
+                        "Allowance not found"
+                    );
+                },
+
+                OperationType::MintKeylessBadge => {
+                    let signer = NonFungibleGlobalId::new(
+                        operation.resource().unwrap(),
+                        operation.non_fungible_ids().unwrap()[0].clone(),
+                    );
+
+                    // Make sure that the signer is a public key not already used by a member
+                    assert!(
+                        signer.resource_address() == SECP256K1_SIGNATURE_RESOURCE ||
+                        signer.resource_address() == ED25519_SIGNATURE_RESOURCE,
+                        "Not a signature badge"
+                    );
+                    assert!(
+                        self.keyless_signers.get(&signer).is_none(),
+                        "Signer already registered"
+                    );
+                },
+
+                OperationType::CallMethod => {
+                    let (method, arguments, withdrawals) = operation.method_call().unwrap();
+
+                    // Verify that the method call is well formed
+                    assert!(
+                        !method.is_empty(),
+                        "No method specified"
+                    );
+                    assert!(
+                        matches!(
+                            scrypto_decode::<ScryptoValue>(&arguments),
+                            Ok(ScryptoValue::Tuple { .. })
+                        ),
+                        "Arguments must be a tuple"
+                    );
+
+                    // Verify that the resources to pass to the method are available
+                    for (resource, specifier) in withdrawals.iter() {
+                        match specifier {
+                            ResourceSpecifier::Fungible(amount) => self.check_availability(
+                                *resource,
+                                Some(*amount),
+                                None,
+                            ),
+                            ResourceSpecifier::NonFungible(non_fungible_ids) => self.check_availability(
+                                *resource,
+                                None,
+                                Some(&non_fungible_ids.iter().cloned().collect()),
+                            ),
+                        }
+                    }
+                },
+
+                OperationType::Swap => {
+                    let resource = operation.resource().unwrap();
+                    let (output_resource, min_output) = operation.output().unwrap();
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        resource,
+                        operation.amount(),
+                        None,
+                    );
+                    assert!(
+                        output_resource.is_fungible() && output_resource != resource,
+                        "Wrong output resource"
+                    );
+                    assert!(
+                        min_output > Decimal::ZERO,
+                        "Minimum output must be positive"
+                    );
+                },
+
+                OperationType::ContributeToPool => {
+                    let pool = operation.component().unwrap();
+                    let contributions = operation.contributions().unwrap();
+
+                    // Make sure that the contributions match the resources of the pool
+                    self.sort_pool_contributions(pool, contributions.clone());
+
+                    // Verify that the operation is possible
+                    for (resource, amount) in contributions.iter() {
+                        self.check_availability(
+                            *resource,
+                            Some(*amount),
+                            None,
+                        );
+                    }
+                },
+
+                OperationType::RedeemFromPool => {
+                    let pool = operation.component().unwrap();
+
+                    // Find the pool unit resource address for the specified pool
+                    let pool_unit_address = self.get_pool_unit_address(pool);
+
+                    // Verify that the operation is possible
+                    self.check_availability(
+                        pool_unit_address,
+                        operation.amount(),
+                        None,
+                    );
+                },
+
+                OperationType::ManageResource => {
+                    let resource = operation.resource().unwrap();
+
+                    // Member badges and allowance badges can only be managed through their own
+                    // operations
+                    assert!(
+                        resource != self.member_badges_resource_manager.address() &&
+                        resource != self.allowance_badges_resource_manager.address(),
+                        "Can't manage the badges of this component"
+                    );
+
+                    // Make sure that the badge, if any, is in the DAO treasury; without a badge
+                    // only the resources created by this component can be managed
+                    match operation.badge() {
+                        None => assert!(
+                            self.created_resources.get(&resource).is_some(),
+                            "Resource not created by this component"
+                        ),
+                        Some((badge, ResourceSpecifier::Fungible(amount))) => self.check_availability(
+                            badge,
+                            Some(amount),
+                            None,
+                        ),
+                        Some((badge, ResourceSpecifier::NonFungible(non_fungible_ids))) => self.check_availability(
+                            badge,
+                            None,
+                            Some(&non_fungible_ids.into_iter().collect()),
+                        ),
+                    }
+
+                    // Verify that the action makes sense and is possible
+                    match operation.resource_action().unwrap() {
+                        ResourceAction::MintFungibles(amount, _) => {
+                            assert!(
+                                resource.is_fungible(),
+                                "Fungible/non fungible mismatch"
+                            );
+                            assert!(
+                                amount > Decimal::ZERO,
+                                "Amount must be positive"
+                            );
+                        },
+                        ResourceAction::MintNonFungible(non_fungible_id, data, _) => {
+                            assert!(
+                                !resource.is_fungible(),
+                                "Fungible/non fungible mismatch"
+                            );
+                            assert!(
+                                !NonFungibleResourceManager::from(resource)
+                                    .non_fungible_exists(&non_fungible_id),
+                                "Non fungible already exists"
+                            );
+                            assert!(
+                                matches!(
+                                    scrypto_decode::<ScryptoValue>(&data),
+                                    Ok(ScryptoValue::Tuple { .. })
+                                ),
+                                "Data must be a tuple"
+                            );
+                        },
+                        ResourceAction::BurnFungibles(amount) => self.check_availability(
+                            resource,
+                            Some(amount),
+                            None,
+                        ),
+                        ResourceAction::BurnNonFungibles(non_fungible_ids) => self.check_availability(
+                            resource,
+                            None,
+                            Some(&non_fungible_ids),
+                        ),
+                        ResourceAction::UpdateNonFungibleData(non_fungible_id, field_name, data) => {
+                            assert!(
+                                !resource.is_fungible(),
+                                "Fungible/non fungible mismatch"
+                            );
+                            assert!(
+                                NonFungibleResourceManager::from(resource)
+                                    .non_fungible_exists(&non_fungible_id),
+                                "Non fungible not found"
+                            );
+                            assert!(
+                                !field_name.is_empty(),
+                                "No field specified"
+                            );
+                            assert!(
+                                scrypto_decode::<ScryptoValue>(&data).is_ok(),
+                                "Data must be SBOR-encoded"
+                            );
+                        },
+                        ResourceAction::SetMetadata(_, _) => {},
+                    }
+                },
+
+                OperationType::CreateResource => {
+
+                    // Verify that the resource makes sense
+                    if let NewResource::Fungible(divisibility, initial_supply, _) =
+                        operation.new_resource().unwrap() {
+                        assert!(
+                            divisibility <= 18,
+                            "Wrong divisibility"
+                        );
+                        assert!(
+                            initial_supply >= Decimal::ZERO,
+                            "Negative amount"
+                        );
+                    }
+                },
+
+                OperationType::Batch => {
+                    let actions = operation.actions().unwrap();
+
+                    // Verify each action as if it was a separate operation
+                    assert!(
+                        !actions.is_empty(),
+                        "Empty batch"
+                    );
+                    for action in actions.iter() {
+                        self.check_operation(
+                            &action.to_operation(&operation.description()),
+                            badge_ids,
+                        );
+                    }
+                },
+            }
+        }
+
+        /* Private method to execute an operation that has been signed by enough members.
+         * Input parameters:
+         * - operation: the operation to execute
+         * Outputs:
+         * - the resources and amounts deposited in the DAO treasury by a ContributeToPool or
+         *   RedeemFromPool operation (or by the actions of a Batch operation)
+         */
+        fn execute_operation(
+            &mut self,
+            operation: &Operation,
+        ) -> Vec<(ResourceAddress, Decimal)> {
+            let mut received: Vec<(ResourceAddress, Decimal)> = vec![];
+
+            match operation.operation_type() {
+
+                OperationType::MintBadge => {
+                    let recipient = operation.recipient().unwrap();
+
+                    // Create a new member badge
+                    let member_badge_bucket = self.mint_badge(recipient, self.member_term);
+
+                    // Send the new member badge to the specified account
+                    self.deposit_or_abort(recipient, member_badge_bucket);
+                },
+
+                OperationType::DisableBadge => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Disable the specified member badge
+                    Member::enable(
+                        &non_fungible_id,
+                        false,
+                        &self.member_badges_resource_manager,
+                    );
+                },
+
+                OperationType::EnableBadge => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Enable the specified member badge
+                    Member::enable(
+                        &non_fungible_id,
+                        true,
+                        &self.member_badges_resource_manager,
+                    );
+                },
+
+                OperationType::IncreaseMinCosigners => {
+                    // Increase the number of required cosigners
+                    self.min_cosigners += 1;
+                },
+
+                OperationType::DecreaseMinCosigners => {
+                    // Decrease the number of required cosigners
+                    self.min_cosigners -= 1;
+                },
+
+                OperationType::SendFungibles => {
+                    let resource = operation.resource().unwrap();
+                    let amount = operation.amount().unwrap();
+                    let recipient = operation.recipient().unwrap();
+
+                    // Use the account badge to withdraw the specified fungibles from the DAO
+                    // treasury and put them in a bucket
+                    let coin_bucket = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || self.account.withdraw(
+                            resource,
+                            amount
+                        )
+                    );
+
+                    // Send the bucket to the specified account or store it in the AccountLocker
+                    self.deposit_or_store(operation, recipient, coin_bucket);
+                },
+
+                OperationType::SendNonFungibles => {
+                    let resource = operation.resource().unwrap();
+                    let non_fungible_ids = operation.non_fungible_ids().unwrap();
+                    let recipient = operation.recipient().unwrap();
+
+                    // Use the account badge to withdraw the specified non fungibles from the DAO
+                    // treasury and put them in a bucket
+                    let non_fungibles_bucket = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || self.account.withdraw_non_fungibles(
+                            resource,
+                            non_fungible_ids
+                        )
+                    );
+
+                    // Send the bucket to the specified account or store it in the AccountLocker
+                    self.deposit_or_store(operation, recipient, non_fungibles_bucket.into());
+                },
+
+                OperationType::TransferAccountBadge => {
+                    let component = operation.component().unwrap();
+
+                    // Put the account badge in a bucket
+                    let account_badge_bucket = self.account_badge.take_all();
+
+                    // Deposit the bucket in the specified component
+                    component.call_ignore_rtn::<(NonFungibleBucket, )>(
+                        "deposit_account_badge",
+                        &(account_badge_bucket, )
+                    );
+                },
+
+                OperationType::Stake => {
+                    let amount = operation.amount().unwrap();
+                    let mut validator = operation.validator().unwrap();
+
+                    // Use the account badge to withdraw the XRD from the DAO treasury, stake them
+                    // and put the LSU back into the account
+                    self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+                            let xrd_bucket = self.account.withdraw(
+                                XRD,
+                                amount,
+                            );
+
+                            let lsu_bucket = validator.stake(FungibleBucket(xrd_bucket));
+
+                            self.account.deposit(lsu_bucket.into());
+                        }
+                    );
+                },
+
+                OperationType::Unstake => {
+                    let amount = operation.amount().unwrap();
+                    let mut validator = operation.validator().unwrap();
+                    let lsu_global_address: GlobalAddress = validator.get_metadata("pool_unit").ok().unwrap().unwrap();
+                    let lsu_address = ResourceAddress::try_from(lsu_global_address).unwrap();
+
+                    // Use the account badge to withdraw the LSU from the DAO treasury, unstake them
+                    // and put the claim NFT back into the account
+                    self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+                            let lsu_bucket = self.account.withdraw(
+                                lsu_address,
+                                amount,
+                            );
+
+                            let claim_nft_bucket = validator.unstake(FungibleBucket(lsu_bucket));
+
+                            self.account.deposit(claim_nft_bucket.into());
+                        }
+                    );
+                },
+
+                OperationType::ClaimUnstakedXrd => {
+                    let non_fungible_ids = operation.non_fungible_ids().unwrap();
+                    let mut validator = operation.validator().unwrap();
+                    let claim_nft_global_address: GlobalAddress = validator.get_metadata("claim_nft").ok().unwrap().unwrap();
+                    let claim_nft_address = ResourceAddress::try_from(claim_nft_global_address).unwrap();
+
+                    // Use the account badge to withdraw the Claim NFT from the DAO treasury, claim
+                    // the XRD and put them back into the account
+                    self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+                            let claim_nft_bucket = self.account.withdraw_non_fungibles(
+                                claim_nft_address,
+                                non_fungible_ids,
+                            );
+
+                            let xrd_bucket = validator.claim_xrd(claim_nft_bucket);
+
+                            self.account.deposit(xrd_bucket.into());
+                        }
+                    );
+                },
+
+                OperationType::SetMemberBadgeMetadata => {
+                    let (key, value) = operation.metadata().unwrap();
+
+                    // Set the metadata on the member badges resource manager (this component is the
+                    // metadata setter)
+                    ScryptoVmV1Api::object_call_module(
+                        self.member_badges_resource_manager.address().as_node_id(),
+                        AttachedModuleId::Metadata,
+                        METADATA_SET_IDENT,
+                        scrypto_encode(
+                            &MetadataSetInput {
+                                key,
+                                value,
+                            }
+                        ).unwrap(),
+                    );
+                },
+
+                OperationType::RenewMember => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+                    let valid_until = operation.date().unwrap();
+
+                    // Set the new end of the member term
+                    Member::renew(
+                        &non_fungible_id,
+                        valid_until,
+                        &self.member_badges_resource_manager,
+                    );
+                },
+
+                OperationType::DisableInactiveMembers => {
+
+                    // Disable the member badges listed in the operation, check_operation verified
+                    // they are still inactive
+                    for non_fungible_id in operation.non_fungible_ids().unwrap().iter() {
+                        Member::enable(
+                            non_fungible_id,
+                            false,
+                            &self.member_badges_resource_manager,
+                        );
+                    }
+                },
+
+                OperationType::InviteMember => {
+                    let recipient = operation.recipient().unwrap();
+                    let expiration = operation.date().unwrap();
+
+                    // Register the invitation; an existing invitation for the same account is
+                    // replaced
+                    self.invitations.insert(recipient, Some(expiration));
+                },
+
+                OperationType::RevokeInvitation => {
+                    let recipient = operation.recipient().unwrap();
+
+                    // Remove the invitation
+                    self.invitations.remove(&recipient);
+                },
+
+                OperationType::ApproveApplication => {
+                    let account = operation.recipient().unwrap();
+
+                    // Give back the bond and send a new member badge to the candidate
+                    self.close_application(account, true);
+
+                    let member_badge_bucket = self.mint_badge(account, self.member_term);
+                    self.deposit_or_abort(account, member_badge_bucket);
+                },
+
+                OperationType::RejectApplication => {
+                    let account = operation.recipient().unwrap();
+
+                    // Remove the application and refund or forfeit the bond
+                    self.close_application(account, !self.forfeit_bonds);
+                },
+
+                OperationType::GrantBudget => {
+                    let non_fungible_id = operation.non_fungible_ids().unwrap()[0].clone();
+                    let resource = operation.resource().unwrap();
+                    let amount = operation.amount().unwrap();
+                    let period = operation.period().unwrap();
+                    let expiration = operation.date().unwrap();
+
+                    // Assign or revoke the budget
+                    if amount == Decimal::ZERO {
+                        self.budgets.remove(&(non_fungible_id, resource));
+                    } else {
+                        self.budgets.insert(
+                            (non_fungible_id, resource),
+                            Budget::new(amount, period, expiration),
+                        );
+                    }
+                },
+
+                OperationType::GrantAllowance => {
+                    let holder = operation.component().unwrap();
+                    let resource = operation.resource().unwrap();
+                    let amount = operation.amount().unwrap();
+                    let period = operation.period().unwrap();
+                    let expiration = operation.date().unwrap();
+
+                    // Mint the allowance badge and register the allowance
+                    let allowance_id = NonFungibleLocalId::Integer(self.next_allowance_id.into());
+                    let allowance_badge_bucket = self.allowance_badges_resource_manager
+                        .mint_non_fungible(
+                            &allowance_id,
+                            AllowanceBadge::new(resource, amount, period, expiration)
+                        );
+                    self.allowances.insert(
+                        allowance_id,
+                        Allowance::new(resource, amount, period, expiration),
+                    );
+
+                    // Get ready for minting the next allowance badge
+                    self.next_allowance_id += 1;
+
+                    // Send the allowance badge to the holder
+                    if holder.address().as_node_id().is_global_account() {
+                        let account: Global<Account> = Global::from(holder.address());
+                        self.deposit_or_abort(account, allowance_badge_bucket.into());
+                    } else {
+                        holder.call_ignore_rtn::<(NonFungibleBucket, )>(
+                            "deposit_allowance_badge",
+                            &(allowance_badge_bucket, )
+                        );
+                    }
+                },
+
+                OperationType::RevokeAllowance => {
+                    let allowance_id = operation.non_fungible_ids().unwrap()[0].clone();
+
+                    // Forget the allowance
+                    self.allowances.remove(&allowance_id);
+                },
+
+                OperationType::MintKeylessBadge => {
+                    let signer = NonFungibleGlobalId::new(
+                        operation.resource().unwrap(),
+                        operation.non_fungible_ids().unwrap()[0].clone(),
+                    );
+
+                    // Mint a new member badge for the keyless member and keep it in the
+                    // component
+                    let badge_id = NonFungibleLocalId::Integer(self.next_badge_id.into());
+                    let member_badge_bucket = self.member_badges_resource_manager.mint_non_fungible(
+                        &badge_id,
+                        Member::new_keyless(self.member_term, signer.clone())
+                    );
+                    self.keyless_member_badges.put(member_badge_bucket);
+                    self.keyless_signers.insert(signer, badge_id);
+
+                    // Get ready for minting the next member badge
+                    self.next_badge_id += 1;
+                },
+
+                OperationType::SendFungiblesMulti => {
+                    let resource = operation.resource().unwrap();
+                    let payments = operation.payments().unwrap();
+                    let total: Decimal = payments.iter()
+                        .fold(Decimal::ZERO, |total, (_, amount)| total + *amount);
+
+                    // Use the account badge to withdraw the total amount from the DAO treasury
+                    let mut coin_bucket = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || self.account.withdraw(
+                            resource,
+                            total
+                        )
+                    );
+
+                    // Send each amount to its recipient or store it in the AccountLocker
+                    for (recipient, amount) in payments.into_iter() {
+                        self.deposit_or_store(operation, recipient, coin_bucket.take(amount));
+                    }
+                    coin_bucket.drop_empty();
+                },
+
+                OperationType::CallMethod => {
+                    let component = operation.component().unwrap();
+                    let (method, arguments, withdrawals) = operation.method_call().unwrap();
+
+                    // Use the account badge to withdraw the specified resources from the DAO
+                    // treasury and call the method, so that the component can also check the
+                    // account badge
+                    self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+
+                            // The buckets are the first arguments of the method, followed by
+                            // the specified arguments
+                            let mut fields: Vec<ScryptoValue> = vec![];
+                            for (resource, specifier) in withdrawals.into_iter() {
+                                let bucket = match specifier {
+                                    ResourceSpecifier::Fungible(amount) =>
+                                        self.account.withdraw(resource, amount),
+                                    ResourceSpecifier::NonFungible(non_fungible_ids) =>
+                                        self.account.withdraw_non_fungibles(
+                                            resource,
+                                            non_fungible_ids.into_iter().collect()
+                                        ).into(),
+                                };
+                                fields.push(
+                                    scrypto_decode(&scrypto_encode(&bucket).unwrap()).unwrap()
+                                );
+                            }
+                            if let ScryptoValue::Tuple { fields: arguments } =
+                                scrypto_decode(&arguments).unwrap() {
+                                fields.extend(arguments);
+                            }
+
+                            let output = ScryptoVmV1Api::object_call(
+                                component.address().as_node_id(),
+                                &method,
+                                scrypto_encode(&ScryptoValue::Tuple { fields }).unwrap(),
+                            );
+
+                            // Put every returned bucket back into the DAO treasury and drop the
+                            // returned proofs; any other returned object makes the operation fail
+                            let output = IndexedScryptoValue::from_vec(output).unwrap();
+                            for node_id in output.owned_nodes().iter() {
+                                let blueprint_id = ScryptoVmV1Api::object_get_blueprint_id(node_id);
+                                let is_bucket =
+                                    blueprint_id == BlueprintId::new(
+                                        &RESOURCE_PACKAGE,
+                                        FUNGIBLE_BUCKET_BLUEPRINT,
+                                    ) ||
+                                    blueprint_id == BlueprintId::new(
+                                        &RESOURCE_PACKAGE,
+                                        NON_FUNGIBLE_BUCKET_BLUEPRINT,
+                                    );
+                                let is_proof =
+                                    blueprint_id == BlueprintId::new(
+                                        &RESOURCE_PACKAGE,
+                                        FUNGIBLE_PROOF_BLUEPRINT,
+                                    ) ||
+                                    blueprint_id == BlueprintId::new(
+                                        &RESOURCE_PACKAGE,
+                                        NON_FUNGIBLE_PROOF_BLUEPRINT,
+                                    );
+                                assert!(
+                                    is_bucket || is_proof,
+                                    "Returned object neither a bucket nor a proof"
+                                );
+
+                                if is_bucket {
+                                    self.account.deposit(Bucket(Own(*node_id)));
+                                } else {
+                                    Proof(Own(*node_id)).drop();
+                                }
+                            }
+                        }
+                    );
+                },
+
+                OperationType::Swap => {
+                    let resource = operation.resource().unwrap();
+                    let amount = operation.amount().unwrap();
+                    let pool = operation.component().unwrap();
+                    let (output_resource, min_output) = operation.output().unwrap();
+
+                    // Use the account badge to withdraw the input fungibles from the DAO treasury
+                    // and put them in a bucket
+                    let input_bucket = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || self.account.withdraw(
+                            resource,
+                            amount
+                        )
+                    );
+
+                    // Swap them on the pool and check the result
+                    let output_bucket: Bucket = pool.call("swap", &(input_bucket, ));
+                    assert!(
+                        output_bucket.resource_address() == output_resource,
+                        "Wrong output resource"
+                    );
+                    assert!(
+                        output_bucket.amount() >= min_output,
+                        "Not enough output"
+                    );
+
+                    // Put the output in the DAO treasury
+                    self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || self.account.deposit(output_bucket)
+                    );
+                },
+
+                OperationType::ContributeToPool => {
+                    let pool = operation.component().unwrap();
+                    let contributions = self.sort_pool_contributions(
+                        pool,
+                        operation.contributions().unwrap(),
+                    );
+
+                    // Use the account badge to withdraw the fungibles to contribute from the DAO
+                    // treasury and contribute them to the pool, so that the pool manager rule can
+                    // also require the account badge
+                    let returned_buckets = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+                            let mut buckets: Vec<FungibleBucket> = contributions.into_iter()
+                                .map(
+                                    |(resource, amount)| FungibleBucket(
+                                        self.account.withdraw(resource, amount)
+                                    )
+                                )
+                                .collect();
+
+                            // The pool units and the eventual change are returned
+                            match pool.address().as_node_id().entity_type() {
+                                Some(EntityType::GlobalOneResourcePool) => {
+                                    let mut pool: Global<OneResourcePool> = Global::from(pool.address());
+                                    vec![pool.contribute(buckets.remove(0))]
+                                },
+                                Some(EntityType::GlobalTwoResourcePool) => {
+                                    let mut pool: Global<TwoResourcePool> = Global::from(pool.address());
+                                    let second_bucket = buckets.remove(1);
+                                    let (pool_units_bucket, change_bucket) =
+                                        pool.contribute((buckets.remove(0), second_bucket));
+                                    let mut returned_buckets = vec![pool_units_bucket];
+                                    returned_buckets.extend(change_bucket);
+                                    returned_buckets
+                                },
+                                _ => {
+                                    let mut pool: Global<MultiResourcePool> = Global::from(pool.address());
+                                    let (pool_units_bucket, change_buckets) = pool.contribute(buckets);
+                                    let mut returned_buckets = vec![pool_units_bucket];
+                                    returned_buckets.extend(change_buckets);
+                                    returned_buckets
+                                },
+                            }
+                        }
+                    );
+
+                    // Put the pool units and the change back into the account
+                    received = self.deposit_pool_buckets(returned_buckets);
+                },
+
+                OperationType::RedeemFromPool => {
+                    let pool = operation.component().unwrap();
+                    let amount = operation.amount().unwrap();
+                    let pool_unit_address = self.get_pool_unit_address(pool);
+
+                    // Use the account badge to withdraw the pool units from the DAO treasury
+                    let pool_units_bucket = FungibleBucket(
+                        self.account_badge.authorize_with_non_fungibles(
+                            &self.account_badge.non_fungible_local_ids(1),
+                            || self.account.withdraw(
+                                pool_unit_address,
+                                amount,
+                            )
+                        )
+                    );
+
+                    // Redeem them
+                    let returned_buckets = match pool.address().as_node_id().entity_type() {
+                        Some(EntityType::GlobalOneResourcePool) => {
+                            let mut pool: Global<OneResourcePool> = Global::from(pool.address());
+                            vec![pool.redeem(pool_units_bucket)]
+                        },
+                        Some(EntityType::GlobalTwoResourcePool) => {
+                            let mut pool: Global<TwoResourcePool> = Global::from(pool.address());
+                            let (first_bucket, second_bucket) = pool.redeem(pool_units_bucket);
+                            vec![first_bucket, second_bucket]
+                        },
+                        _ => {
+                            let mut pool: Global<MultiResourcePool> = Global::from(pool.address());
+                            pool.redeem(pool_units_bucket)
+                        },
+                    };
+
+                    // Put the redeemed resources back into the account
+                    received = self.deposit_pool_buckets(returned_buckets);
+                },
+
+                OperationType::ManageResource => {
+                    let resource = operation.resource().unwrap();
+                    let resource_manager = ResourceManager::from(resource);
+                    let resource_action = operation.resource_action().unwrap();
+
+                    // Use the account badge to create a proof of the badge held in the DAO
+                    // treasury, if any, and perform the action with the proof in the auth zone;
+                    // without a badge this component is the global caller that performs the
+                    // action. The minted resources, if any, are returned
+                    let minted_bucket = self.account_badge.authorize_with_non_fungibles(
+                        &self.account_badge.non_fungible_local_ids(1),
+                        || {
+                            let badge_proof: Option<Proof> = match operation.badge() {
+                                None => None,
+                                Some((badge, ResourceSpecifier::Fungible(amount))) =>
+                                    Some(self.account.create_proof_of_amount(badge, amount).into()),
+                                Some((badge, ResourceSpecifier::NonFungible(non_fungible_ids))) =>
+                                    Some(
+                                        self.account.create_proof_of_non_fungibles(
+                                            badge,
+                                            non_fungible_ids.into_iter().collect()
+                                        ).into()
+                                    ),
+                            };
+
+                            let mut perform_action =
+                                || match resource_action.clone() {
+                                    ResourceAction::MintFungibles(amount, _) =>
+                                        Some(FungibleResourceManager::from(resource).mint(amount).into()),
+                                    ResourceAction::MintNonFungible(non_fungible_id, data, _) => {
+                                        let mut entries = index_map_new();
+                                        entries.insert(
+                                            non_fungible_id,
+                                            (scrypto_decode::<ScryptoValue>(&data).unwrap(), )
+                                        );
+                                        let output = ScryptoVmV1Api::object_call(
+                                            resource.as_node_id(),
+                                            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT,
+                                            scrypto_encode(
+                                                &NonFungibleResourceManagerMintInput {
+                                                    entries,
+                                                }
+                                            ).unwrap(),
+                                        );
+                                        Some(scrypto_decode::<Bucket>(&output).unwrap())
+                                    },
+                                    ResourceAction::BurnFungibles(amount) => {
+                                        resource_manager.burn(self.account.withdraw(resource, amount));
+                                        None
+                                    },
+                                    ResourceAction::BurnNonFungibles(non_fungible_ids) => {
+                                        resource_manager.burn(
+                                            self.account.withdraw_non_fungibles(resource, non_fungible_ids)
+                                        );
+                                        None
+                                    },
+                                    ResourceAction::UpdateNonFungibleData(non_fungible_id, field_name, data) => {
+                                        NonFungibleResourceManager::from(resource).update_non_fungible_data(
+                                            &non_fungible_id,
+                                            &field_name,
+                                            scrypto_decode::<ScryptoValue>(&data).unwrap(),
+                                        );
+                                        None
+                                    },
+                                    ResourceAction::SetMetadata(key, value) => {
+                                        ScryptoVmV1Api::object_call_module(
+                                            resource.as_node_id(),
+                                            AttachedModuleId::Metadata,
+                                            METADATA_SET_IDENT,
+                                            scrypto_encode(
+                                                &MetadataSetInput {
+                                                    key,
+                                                    value,
+                                                }
+                                            ).unwrap(),
+                                        );
+                                        None
+                                    },
+                                };
+
+                            match badge_proof {
+                                None => perform_action(),
+                                Some(badge_proof) => {
+                                    let minted_bucket = badge_proof.authorize(perform_action);
+                                    badge_proof.drop();
+                                    minted_bucket
+                                },
+                            }
+                        }
+                    );
+
+                    // Send the minted resources to the recipient
+                    if let Some(minted_bucket) = minted_bucket {
+                        self.deposit_or_abort(resource_action.recipient().unwrap(), minted_bucket);
+                    }
+                },
+
+                OperationType::CreateResource => {
+                    let dao_rule = rule!(require(global_caller(Runtime::global_address())));
+
+                    let new_resource = operation.new_resource().unwrap();
+
+                    // The metadata can be updated by this component only
+                    let metadata = match new_resource.clone() {
+                        NewResource::Fungible(_, _, metadata) => metadata,
+                        NewResource::NonFungible(metadata) => metadata,
+                    };
+                    let mut resource_metadata = metadata!(
+                        roles {
+                            metadata_setter => dao_rule.clone();
+                            metadata_setter_updater => dao_rule.clone();
+                            metadata_locker => dao_rule.clone();
+                            metadata_locker_updater => dao_rule.clone();
+                        }
+                    );
+                    for (key, value) in metadata.into_iter() {
+                        resource_metadata.init.set_metadata(key, value);
+                    }
+
+                    // Create the resource; its owner and every role are this component
+                    let resource = match new_resource {
+                        NewResource::Fungible(divisibility, initial_supply, _) => {
+                            let resource_builder = ResourceBuilder::new_fungible(
+                                OwnerRole::Fixed(dao_rule.clone())
+                            )
+                                .divisibility(divisibility)
+                                .metadata(resource_metadata)
+                                .mint_roles(mint_roles!(
+                                    minter => dao_rule.clone();
+                                    minter_updater => dao_rule.clone();
+                                ))
+                                .burn_roles(burn_roles!(
+                                    burner => dao_rule.clone();
+                                    burner_updater => dao_rule.clone();
+                                ));
+
+                            // Put the initial supply in the DAO treasury
+                            if initial_supply > Decimal::ZERO {
+                                let initial_supply_bucket = resource_builder.mint_initial_supply(initial_supply);
+                                let resource = initial_supply_bucket.resource_address();
+                                self.account_badge.authorize_with_non_fungibles(
+                                    &self.account_badge.non_fungible_local_ids(1),
+                                    || self.account.deposit(initial_supply_bucket.into())
+                                );
+                                received.push((resource, initial_supply));
+
+                                resource
+                            } else {
+                                resource_builder.create_with_no_initial_supply().address()
+                            }
+                        },
+                        NewResource::NonFungible(_) =>
+                            ResourceBuilder::new_integer_non_fungible::<DaoNonFungible>(
+                                OwnerRole::Fixed(dao_rule.clone())
+                            )
+                                .metadata(resource_metadata)
+                                .mint_roles(mint_roles!(
+                                    minter => dao_rule.clone();
+                                    minter_updater => dao_rule.clone();
+                                ))
+                                .burn_roles(burn_roles!(
+                                    burner => dao_rule.clone();
+                                    burner_updater => dao_rule.clone();
+                                ))
+                                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                                    non_fungible_data_updater => dao_rule.clone();
+                                    non_fungible_data_updater_updater => dao_rule.clone();
+                                ))
+                                .create_with_no_initial_supply()
+                                .address(),
+                    };
+
+                    self.created_resources.insert(resource, ());
+
+                    Operation::emit_resource_created_event(resource);
+                },
+
+                OperationType::Batch => {
+
+                    // Execute the actions in order; each of them is verified again against the
+                    // state left by the previous ones, if any of them fails the whole transaction
+                    // fails
+                    for action in operation.actions().unwrap().iter() {
+                        let action_operation = action.to_operation(&operation.description());
+                        self.check_operation(&action_operation, &[]);
+                        received.extend(self.execute_operation(&action_operation));
+                    }
+                },
+            }
+
+            received
+        }
+
+        /* Private method to deposit a bucket in an account for operations that can't complete
+         * without the deposit. Like deposit_or_refund it presents the depositor badge, but if the
+         * account refuses the deposit the whole transaction is aborted instead of the bucket
+         * being returned to the caller.
+         * Input parameters:
+         * - recipient: the account to deposit the bucket in
+         * - bucket: the fungibles or non fungibles to deposit
+         */
+        fn deposit_or_abort(
+            &self,
+            mut recipient: Global<Account>,
+            bucket: Bucket,
+        ) {
+            let depositor_badge = ResourceOrNonFungible::Resource(
+                self.depositor_badge.resource_address()
+            );
+            self.depositor_badge.authorize_with_amount(
+                1,
+                || recipient.try_deposit_or_abort(
+                    bucket,
+                    Some(depositor_badge)
+                )
+            );
+        }
+
+        /* Private method to deposit a bucket in an account presenting the depositor badge, so
+         * that the deposit is accepted by accounts that added it to their authorized depositors.
+         * Input parameters:
+         * - recipient: the account to deposit the bucket in
+         * - bucket: the fungibles or non fungibles to deposit
+         * Outputs:
+         * - the bucket if the account refused the deposit, None otherwise
+         */
+        fn deposit_or_refund(
+            &self,
+            mut recipient: Global<Account>,
+            bucket: Bucket,
+        ) -> Option<Bucket> {
+            let depositor_badge = ResourceOrNonFungible::Resource(
+                self.depositor_badge.resource_address()
+            );
+            self.depositor_badge.authorize_with_amount(
+                1,
+                || recipient.try_deposit_or_refund(
+                    bucket,
+                    Some(depositor_badge)
+                )
+            )
+        }
+
+        /* Private method to send a payment of a SendFungibles, SendNonFungibles or
+         * SendFungiblesMulti operation to its recipient.
+         * If the recipient refuses the deposit, the operation fails if the DAO wallet was
+         * instantiated with abort_refused_deposits, otherwise the bucket is stored in the
+         * AccountLocker for him to claim later and a PaymentStoredEvent is emitted.
+         * Input parameters:
+         * - operation: the operation being executed
+         * - recipient: the account to send the bucket to
+         * - bucket: the fungibles or non fungibles to send
+         */
+        fn deposit_or_store(
+            &mut self,
+            operation: &Operation,
+            recipient: Global<Account>,
+            bucket: Bucket,
+        ) {
+            let refund = self.deposit_or_refund(recipient, bucket);
+            if let Some(refund) = refund {
+                assert!(
+                    !self.abort_refused_deposits,
+                    "Deposit refused"
+                );
+
+                let amount = if refund.resource_address().is_fungible() {
+                    Some(refund.amount())
+                } else {
+                    None
+                };
+                self.account_locker.store(recipient, refund, false);
+                operation.emit_payment_stored_event(recipient, amount);
+            }
+        }
+
+        /* Internal method to verify that it is possible to withdraw the specified resource from
+         * the account.
+         * There's no output, the method panics if the operation is impossible.
+         * Input parameters:
+         * - resource: the address of the resource to withdraw
+         * - amount: the amount to withdraw if resource is fungible, None otherways
+         * - non_fungible_ids: the list of non fungibles to withdraw or None
+         */
+        fn check_availability(
+            &self,
+            resource: ResourceAddress,
+            amount: Option<Decimal>,
+            non_fungible_ids: Option<&Vec<NonFungibleLocalId>>,
+        ) {
+
+            // The account badge is needed in order to withdraw anything
+            assert!(
+                !self.account_badge.is_empty(),
+                "The account badge is missing"
+            );
+
+            // Fungibles/non fungible specific checks
+            match resource.is_fungible() {
+                true => {
+                    let amount = amount.expect("No amount specified");
+
+                    assert!(
+                        amount > Decimal::ZERO,
+                        "Amount must be positive"
+                    );
+                    assert!(
+                        self.account.balance(resource) >= amount,
+                        "Not enough funds!"
+                    );
+                    assert!(
+                        non_fungible_ids.is_none(),
+                        "Fungible/non fungible mismatch"
+                    );
+                },
+
+                false => {
+                    let non_fungible_ids = non_fungible_ids.expect("No ids specified");
+
+                    assert!(
+                        !non_fungible_ids.is_empty(),
+                        "I'm not sending zero NFTs"
+                    );
+                    for non_fungible_id in non_fungible_ids.iter() {
+                        assert!(
+                            self.account.has_non_fungible(
+                                resource,
+                                non_fungible_id.clone()
+                            ),
+                            "We don't have that NFT"
+                        );
+                    }
+                    assert!(
+                        amount.is_none(),
+                        "Fungible/non fungible mismatch"
+                    );
+                }
+            }
+        }
+
+
+        /* Private method to verify that the resources to contribute to a native pool are
+         * exactly the resources of the pool and to sort them in the order the pool expects.
+         * The method panics if the component is not a native pool or the resources don't match;
+         * a OneResourcePool doesn't expose its resource, it verifies it on contribution.
+         * Input parameters:
+         * - pool: a OneResourcePool, TwoResourcePool or MultiResourcePool component
+         * - contributions: the list of resources and amounts to contribute
+         * Outputs:
+         * - the list of resources and amounts in the order of the resources of the pool
+         */
+        fn sort_pool_contributions(
+            &self,
+            pool: Global<AnyComponent>,
+            contributions: Vec<(ResourceAddress, Decimal)>,
+        ) -> Vec<(ResourceAddress, Decimal)> {
+            let pool_resources: Vec<ResourceAddress> = match pool.address().as_node_id().entity_type() {
+                Some(EntityType::GlobalOneResourcePool) => {
+                    assert!(
+                        contributions.len() == 1,
+                        "Wrong contributions"
+                    );
+                    return contributions;
+                },
+                Some(EntityType::GlobalTwoResourcePool) => {
+                    let pool: Global<TwoResourcePool> = Global::from(pool.address());
+                    pool.get_vault_amounts().keys().cloned().collect()
+                },
+                Some(EntityType::GlobalMultiResourcePool) => {
+                    let pool: Global<MultiResourcePool> = Global::from(pool.address());
+                    pool.get_vault_amounts().keys().cloned().collect()
+                },
+                _ => Runtime::panic("Not a native pool".to_string()),
+            };
+
+            assert!(
+                contributions.len() == pool_resources.len(),
+                "Wrong contributions"
+            );
+            pool_resources.iter()
+                .map(
+                    |pool_resource| *contributions.iter()
+                        .find(|(resource, _)| resource == pool_resource)
+                        .expect("Wrong contributions")
+                )
+                .collect()
+        }
+
+        /* Private method to find the pool unit resource of a native pool.
+         * The method panics if the component is not a native pool.
+         * Input parameters:
+         * - pool: a OneResourcePool, TwoResourcePool or MultiResourcePool component
+         * Outputs:
+         * - the resource address of the pool units
+         */
+        fn get_pool_unit_address(
+            &self,
+            pool: Global<AnyComponent>,
+        ) -> ResourceAddress {
+            assert!(
+                matches!(
+                    pool.address().as_node_id().entity_type(),
+                    Some(EntityType::GlobalOneResourcePool) |
+                    Some(EntityType::GlobalTwoResourcePool) |
+                    Some(EntityType::GlobalMultiResourcePool)
+                ),
+                "Not a native pool"
+            );
+
+            let pool_unit_global_address: GlobalAddress = pool.get_metadata("pool_unit").ok().unwrap().unwrap();
+            ResourceAddress::try_from(pool_unit_global_address).unwrap()
+        }
+
+        /* Private method to put the buckets returned by a native pool into the account; empty
+         * buckets are dropped.
+         * Input parameters:
+         * - buckets: pool units, change or redeemed resources
+         * Outputs:
+         * - the resources and amounts deposited in the account
+         */
+        fn deposit_pool_buckets(
+            &mut self,
+            buckets: Vec<FungibleBucket>,
+        ) -> Vec<(ResourceAddress, Decimal)> {
+            let mut deposited: Vec<(ResourceAddress, Decimal)> = vec![];
+
+            self.account_badge.authorize_with_non_fungibles(
+                &self.account_badge.non_fungible_local_ids(1),
+                || {
+                    for bucket in buckets.into_iter() {
+                        if bucket.is_empty() {
+                            bucket.drop_empty();
+                        } else {
+                            deposited.push((bucket.resource_address(), bucket.amount()));
+                            self.account.deposit(bucket.into());
+                        }
+                    }
+                }
+            );
+
+            deposited
+        }
+        /* A member can invoke this method to create/sign an operation to mint a new member badge
+         * and send it to the specified account.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - recipient: the account address to send the new member badge to
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn mint_member_badge(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            recipient: Global<Account>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::MintBadge(recipient),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* Private method to mint a new member badge.
+         * Input parameters:
+         * - account: the account the new member badge is for
+         * - term: duration in seconds of the member term or None if the badge never expires
+         * Outputs:
+         * - a bucket containing the new member badge
+         */
+        fn mint_badge(
+            &mut self,
+            account: Global<Account>,
+            term: Option<i64>,
+        ) -> Bucket {
+            let member_badge_bucket = self.member_badges_resource_manager.mint_non_fungible(
+                &NonFungibleLocalId::Integer(self.next_badge_id.into()),
+                Member::new(term, account)
+            );
+
+            // Get ready for minting the next member badge
+            self.next_badge_id += 1;
+
+            member_badge_bucket.into()
+        }
+
+        /* A member can invoke this method to create/sign an operation to disable a member badge.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - non_fungible_id: the id of the member badge to disable
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn disable_member_badge(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            non_fungible_id: NonFungibleLocalId,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::DisableBadge(non_fungible_id.clone()),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to enable a member badge
+         * that has been previously disabled.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - non_fungible_id: the id of the member badge to enable
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn enable_member_badge(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            non_fungible_id: NonFungibleLocalId,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::EnableBadge(non_fungible_id.clone()),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to increase the minimum
+         * number of cosigners for future operations.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn increase_min_cosigners(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::IncreaseMinCosigners,
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to decrease the minimum
+         * number of cosigners for future operations.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn decrease_min_cosigners(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::DecreaseMinCosigners,
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to send a bucket of
+         * fungibles to an account.
+         * If the recipient refuses the deposit, the operation fails if the DAO wallet was
+         * instantiated with abort_refused_deposits, otherwise the bucket is stored in the
+         * AccountLocker (see get_account_locker) for him to claim.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - resource: resource address of the fungibles to send
+         * - amount: the amount of fungibles to send
+         * - recipient: the account address to send the fungibles to
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn send_fungibles(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            resource: ResourceAddress,
+            amount: Decimal,
+            recipient: Global<Account>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::SendFungibles(resource, amount, recipient),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to send fungibles to
+         * several accounts at once.
+         * A recipient refusing the deposit makes the whole operation fail if the DAO wallet was
+         * instantiated with abort_refused_deposits, otherwise his payment is stored in the
+         * AccountLocker for him to claim and a PaymentStoredEvent is emitted.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - resource: resource address of the fungibles to send
+         * - payments: the list of recipient accounts and amounts to send them
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn send_fungibles_multi(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            resource: ResourceAddress,
+            payments: Vec<(Global<Account>, Decimal)>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::SendFungiblesMulti(resource, payments),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to send a bucket of
+         * non fungibles to an account.
+         * If the recipient refuses the deposit, the operation fails if the DAO wallet was
+         * instantiated with abort_refused_deposits, otherwise the bucket is stored in the
+         * AccountLocker (see get_account_locker) for him to claim.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - resource: resource address of the non fungibles to send
+         * - non_fungible_ids: the list of non fungible ids to send
+         * - recipient: the account address to send the non fungibles to
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn send_non_fungibles(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            resource: ResourceAddress,
+            non_fungible_ids: Vec<NonFungibleLocalId>,
+            recipient: Global<Account>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::SendNonFungibles(resource, non_fungible_ids.clone(), recipient),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* Anyone can invoke this method to get the AccountLocker where the payments refused by
+         * their recipients are stored; a recipient can claim them from there.
+         * Outputs:
+         * - the AccountLocker component
+         */
+        pub fn get_account_locker(&self) -> Global<AccountLocker> {
+            self.account_locker
+        }
+
+        /* Anyone can invoke this method to get the resource address of the badge this component
+         * presents when depositing; an account that adds it to its authorized depositors
+         * receives the DAO payments even if it refuses third party deposits.
+         * Outputs:
+         * - the resource address of the depositor badge
+         */
+        pub fn get_depositor_badge(&self) -> ResourceAddress {
+            self.depositor_badge.resource_address()
+        }
+
+        /* A member can invoke this method to create/sign an operation to call a method of any
+         * component, for example to swap on a DEX or to provide liquidity.
+         * The method is called while the account badge is in the auth zone; the specified
+         * resources are withdrawn from the DAO treasury and passed to the method as its first
+         * arguments, followed by the SBOR-encoded arguments. Every bucket returned by the method
+         * is deposited back into the DAO treasury and every returned proof is dropped; any other
+         * returned object makes the operation fail.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - component: address of the component to call
+         * - method: name of the method to call
+         * - arguments: SBOR-encoded tuple of the arguments following the buckets
+         * - withdrawals: the resources and amounts or non fungible ids to pass as buckets
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn call_method(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            component: Global<AnyComponent>,
+            method: String,
+            arguments: Vec<u8>,
+            withdrawals: Vec<(ResourceAddress, ResourceSpecifier)>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::CallMethod(component, method, arguments, withdrawals),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to swap fungibles from the
+         * DAO treasury on a pool; the operation fails if the pool returns less than the minimum
+         * output amount.
+         * The swap method of the pool must take the input bucket and return the output bucket.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - pool: address of the pool component
+         * - input_resource: resource address of the fungibles to swap
+         * - input_amount: amount of fungibles to swap
+         * - output_resource: resource address of the fungibles to receive
+         * - min_output: the minimum amount of fungibles to receive
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn swap(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            pool: Global<AnyComponent>,
+            input_resource: ResourceAddress,
+            input_amount: Decimal,
+            output_resource: ResourceAddress,
+            min_output: Decimal,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::Swap(pool, input_resource, input_amount, output_resource, min_output),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to contribute fungibles
+         * from the DAO treasury to a native OneResourcePool, TwoResourcePool or
+         * MultiResourcePool; the pool units and the eventual change are put back into the
+         * treasury.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - pool: address of the native pool component
+         * - contributions: the list of resources of the pool and amounts to contribute
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn contribute_to_pool(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            pool: Global<AnyComponent>,
+            contributions: Vec<(ResourceAddress, Decimal)>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::ContributeToPool(pool, contributions),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to redeem pool units held
+         * in the DAO treasury from a native OneResourcePool, TwoResourcePool or
+         * MultiResourcePool; the redeemed resources are put back into the treasury.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - pool: address of the native pool component
+         * - amount: amount of pool units to redeem
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn redeem_from_pool(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            pool: Global<AnyComponent>,
+            amount: Decimal,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::RedeemFromPool(pool, amount),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to manage a resource
+         * through a badge held in the DAO treasury: when the operation is executed a proof of
+         * the badge is created from the account and the action is performed with it in the auth
+         * zone, so it must satisfy the role of the resource the action requires.
+         * The member badges and the allowance badges of this component can't be managed this way.
+         * Without a badge the action relies on the authority of this component as global
+         * caller; this is possible only for the resources created by a CreateResource operation.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - resource: address of the resource to manage
+         * - badge: resource address and amount or non fungible ids of the badge held in the DAO
+         *   treasury to create a proof of, or None
+         * - resource_action: the action to perform (mint, burn, update non fungible data or set
+         *   metadata)
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn manage_resource(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            resource: ResourceAddress,
+            badge: Option<(ResourceAddress, ResourceSpecifier)>,
+            resource_action: ResourceAction,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::ManageResource(resource, badge, resource_action),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to create a new fungible
+         * or non fungible resource owned by the DAO: this component is the owner of the resource
+         * and the only one allowed to mint, burn, update non fungible data and metadata, so
+         * the resource can be managed through ManageResource operations without a badge.
+         * The initial supply of a fungible resource is deposited in the DAO treasury; the
+         * ResourceCreatedEvent reports the address of the new resource.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - new_resource: the kind of resource to create with its divisibility and initial supply
+         *   (fungible only) and its metadata
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn create_resource(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            new_resource: NewResource,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::CreateResource(new_resource),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* One or more members can invoke this method to create/sign an operation of any type in a
+         * single transaction; each distinct enabled member badge counts as a cosigner and the
+         * operation is executed as soon as enough members signed it.
+         * Input parameters:
+         * - member_badge_proofs: proofs of ownership of the member badges
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn sign_operation(
+            &mut self,
+            member_badge_proofs: Vec<Proof>,
+            description: String,
+            details: OperationDetails,
+        ) -> Hash {
+
+            // Get the ids of the member badges
+            let badge_ids = self.get_badge_ids(member_badge_proofs);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                details,
+            );
+
+            // Register the operation in the operations KVS or add the cosigners if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, badge_ids)
+        }
+
+        /* One or more members can invoke this method to create/sign an operation of any type
+         * without passing proofs: the member badges are looked for in the auth zone of the
+         * transaction, so a manifest only needs to create the proofs before calling this method.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_ids: the non fungible ids of the member badges in the auth zone
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn sign_operation_with_auth_zone(
+            &mut self,
+            badge_ids: Vec<NonFungibleLocalId>,
+            description: String,
+            details: OperationDetails,
+        ) -> Hash {
+
+            // Verify that the member badges are in the auth zone
+            let badge_ids = self.get_badge_ids_from_auth_zone(badge_ids);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                details,
+            );
+
+            // Register the operation in the operations KVS or add the cosigners if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, badge_ids)
+        }
+
+        /* A member can invoke this method to remove his signature from an operation.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         */
+        pub fn remove_signature(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            details: OperationDetails,
+        ) {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            self.remove_signature_of(badge_id, description, details);
+        }
+
+        /* A member can invoke this method to remove his signature from an operation without
+         * passing a proof: the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         */
+        pub fn remove_signature_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            description: String,
+            details: OperationDetails,
+        ) {
+
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
+
+            self.remove_signature_of(badge_id, description, details);
+        }
+
+        /* Private method to remove the signature of a member from an operation.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         */
+        fn remove_signature_of(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            description: String,
+            details: OperationDetails,
+        ) {
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                details,
+            );
+
+            // Record the activity of the member
+            Member::update_last_active(&badge_id, &self.member_badges_resource_manager);
+
+            // Create the cosigner; cosigners are compared by badge id only
+            let cosigner = Cosigner::new(badge_id.clone(), false, None);
+
+            // Remove the cosigner from the list for the specified operation, together with the
+            // signatures added on behalf of his delegators
+            let mut cosigners = self.operations
+                .get_mut(&operation)
+                .expect("Operation not found");
+            let was_present = cosigners.swap_remove(&cosigner);
+            assert!(
+                was_present,
+                "Non existing signature"
+            );
+            cosigners.retain(
+                |cosigner: &Cosigner| !cosigner.is_delegated_to(&badge_id)
+            );
+
+            // Increase the nonce of the operation so that an off-ledger signature of the member
+            // can't be used again to restore the removed signature
+            self.operation_hashes.get_mut(&operation.hash()).unwrap().1 += 1;
+        }
+
+        /* A member can invoke this method to create/sign an operation to dismiss this component
+         * and send the account badge to a new one.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - component: address of the component to send the account badge
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn transfer_account_badge(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            component: Global<AnyComponent>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::TransferAccountBadge(component),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to stake some XRD to a
+         * validator.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - amount: the XRD amount to stake
+         * - validator: address of the validator to stake to
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn stake(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            amount: Decimal,
+            validator: Global<Validator>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::Stake(amount, validator),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to start the unstake of
+         * some XRD from a validator.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - amount: the LSU amount to unstake
+         * - validator: address of the validator to unstake from
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn unstake(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            amount: Decimal,
+            validator: Global<Validator>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::Unstake(amount, validator),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to complete the unstake of
+         * some XRD from a validator.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - non_fungible_ids: ids of the NFT to claim
+         * - validator: address of the validator to unstake from
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn claim_unstaked_xrd(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            non_fungible_ids: Vec<NonFungibleLocalId>,
+            validator: Global<Validator>,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::ClaimUnstakedXrd(non_fungible_ids.clone(), validator),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to set a metadata of the
+         * member badges.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - key: the name of the metadata to set
+         * - value: the value of the metadata
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn set_member_badge_metadata(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            key: String,
+            value: MetadataValue,
+        ) -> Hash {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::SetMemberBadgeMetadata(key.clone(), value.clone()),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to set a new end of the
+         * term of a member badge.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - non_fungible_id: the id of the member badge to renew
+         * - valid_until: the new end of the member term
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn renew_member(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            non_fungible_id: NonFungibleLocalId,
+            valid_until: Instant,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::RenewMember(non_fungible_id.clone(), valid_until),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* Anyone can invoke this method to disable the member badges whose term is over.
+         * A MemberExpiredEvent is emitted for each disabled badge; as in the DisableBadge
+         * operation, badges are not disabled if this would leave less than min_cosigners enabled
+         * member badges.
+         * Outputs:
+         * - the list of the disabled member badges
+         */
+        pub fn expire_members(&mut self) -> Vec<NonFungibleLocalId> {
+            Member::disable_expired_members(
+                &self.member_badges_resource_manager,
+                self.min_cosigners,
+                self.probation_period,
+                self.founding_members,
+            )
+        }
+
+        /* Private method to verify that enough member badges remain enabled after disabling the
+         * inactive ones.
+         * Input parameters:
+         * - inactive_members: the number of member badges to disable
+         */
+        fn check_inactive_members(
+            &self,
+            inactive_members: usize,
+        ) {
+            assert!(
+                inactive_members > 0,
+                "No inactive members"
+            );
+
+            let enabled_members = Member::count_enabled_members(
+                &self.member_badges_resource_manager,
+                self.probation_period,
+                self.founding_members,
+            );
+            assert!(
+                enabled_members >= self.min_cosigners + inactive_members,
+                "Not enough enabled members remaining"
+            );
+        }
+
+        /* A member can invoke this method to create/sign an operation to disable the listed member
+         * badges that have not signed or unsigned any operation for longer than the specified
+         * period.
+         * The list of inactive member badges can be obtained through get_inactive_members; every
+         * listed badge must still be inactive when the operation is signed or executed.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - inactivity_period: number of seconds without activity
+         * - badge_ids: the ids of the inactive member badges to disable
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn disable_inactive_members(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            inactivity_period: i64,
+            badge_ids: Vec<NonFungibleLocalId>,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::DisableInactiveMembers(inactivity_period, badge_ids),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* Anyone can invoke this method to get the list of the enabled member badges that have not
+         * signed or unsigned any operation for longer than the specified period.
+         * Input parameters:
+         * - inactivity_period: number of seconds without activity
+         * Outputs:
+         * - the list of the inactive member badges and the last time each of them was active
+         */
+        pub fn get_inactive_members(
+            &self,
+            inactivity_period: i64,
+        ) -> Vec<(NonFungibleLocalId, Instant)> {
+            Member::inactive_members(&self.member_badges_resource_manager, inactivity_period)
+        }
+
+        /* A member can invoke this method to create/sign an operation to invite an account to
+         * claim a new member badge.
+         * Unlike mint_member_badge, this works for accounts that don't accept third party
+         * deposits: the member badge is minted when the invited account claims it.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - recipient: the account to invite
+         * - expiration: the invitation can't be claimed after this date
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn invite_member(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            recipient: Global<Account>,
+            expiration: Instant,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::InviteMember(recipient, expiration),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* A member can invoke this method to create/sign an operation to revoke a pending
+         * invitation.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - recipient: the invited account
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn revoke_invitation(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            recipient: Global<Account>,
+        ) -> Hash {
+
+            // Get the id of the signing member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            // Create the operation
+            let operation = Operation::new(
+                description.clone(),
+                OperationDetails::RevokeInvitation(recipient),
+            );
+
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
+
+        /* The owner of an invited account can invoke this method to claim the member badge.
+         * The badge of a founding member who refused it at instantiation has no term, but unlike
+         * the badges sent at instantiation it goes through the probation period.
+         * Input parameters:
+         * - account: the invited account; the transaction must satisfy its owner role
+         * Outputs:
+         * - a bucket containing the new member badge
+         */
+        pub fn claim_invitation(
+            &mut self,
+            account: Global<Account>,
+        ) -> Bucket {
+
+            // Make sure that the caller owns the invited account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            // Make sure that a valid invitation exists and remove it
+            let expiration = self.invitations.remove(&account).expect("Invitation not found");
+            let term = match expiration {
+                None => None,
+                Some(expiration) => {
+                    assert!(
+                        Clock::current_time_is_strictly_before(expiration, TimePrecision::Second),
+                        "Expired invitation"
+                    );
+
+                    self.member_term
+                },
+            };
+
+            // Create the new member badge
+            self.mint_badge(account, term)
+        }
+
+        /* Anyone can invoke this method to remove an expired invitation from the component
+         * state.
+         * Input parameters:
+         * - account: the invited account
+         */
+        pub fn expire_invitation(
+            &mut self,
+            account: Global<Account>,
+        ) {
+            let expiration = self.invitations.remove(&account).expect("Invitation not found");
+            assert!(
+                expiration.is_some_and(
+                    |expiration| Clock::current_time_is_at_or_after(expiration, TimePrecision::Second)
                 ),
-                "Member badge already enabled"
+                "Invitation not expired yet"
+            );
+        }
+
+        /* Anyone can invoke this method to get the expiration of the pending invitation of an
+         * account.
+         * Input parameters:
+         * - account: the invited account
+         * Outputs:
+         * - None if there is no such invitation, Some(None) for the invitation of a founding
+         *   member, that never expires, or Some(expiration)
+         */
+        pub fn get_invitation(
+            &self,
+            account: Global<Account>,
+        ) -> Option<Option<Instant>> {
+            self.invitations.get(&account).map(|expiration| *expiration)
+        }
+
+        /* Anyone can invoke this method to get the pending application of an account.
+         * Input parameters:
+         * - account: the account of the candidate
+         * Outputs:
+         * - the message of the candidate, the amount of the bond and the expiration of the
+         *   application or None if there is no such application
+         */
+        pub fn get_application(
+            &self,
+            account: Global<Account>,
+        ) -> Option<(String, Decimal, Instant)> {
+            self.applications.get(&account).map(
+                |application| (application.message(), application.bond(), application.expiration())
+            )
+        }
+
+        /* Anyone can invoke this method to apply for membership.
+         * Members can't apply, neither with an account holding a member badge nor with an
+         * account registered for a member badge.
+         * Input parameters:
+         * - account: the account of the candidate; the transaction must satisfy its owner role
+         * - message: message of the candidate to the members
+         * - bond: bucket of XRD to lock until the application is approved, rejected or expired;
+         *   it can be None if no bond is required
+         */
+        pub fn apply_for_membership(
+            &mut self,
+            account: Global<Account>,
+            message: String,
+            bond: Option<Bucket>,
+        ) {
+
+            // Make sure that the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            // Make sure that the candidate has no pending application
+            assert!(
+                self.applications.get(&account).is_none(),
+                "Application already exists"
+            );
+
+            // Make sure that the candidate is not a member already
+            assert!(
+                account.balance(self.member_badges_resource_manager.address()).is_zero() &&
+                !Member::is_member_account(&self.member_badges_resource_manager, account),
+                "Already a member"
+            );
+
+            // Verify the bond and put it in the bonds vault
+            let bond_amount = match bond {
+                None => Decimal::ZERO,
+                Some(bond) => {
+                    assert!(
+                        bond.resource_address() == XRD,
+                        "The bond must be in XRD"
+                    );
+                    let bond_amount = bond.amount();
+                    self.application_bonds.put(bond);
+                    bond_amount
+                },
+            };
+            assert!(
+                bond_amount >= self.application_bond,
+                "Insufficient bond"
+            );
+
+            // Register the application
+            self.applications.insert(
+                account,
+                Application::new(
+                    account,
+                    message,
+                    bond_amount,
+                    self.application_period,
+                )
+            );
+        }
+
+        /* Private method to check that a valid application exists for an account.
+         * Input parameters:
+         * - account: the account of the candidate
+         */
+        fn check_application(
+            &self,
+            account: Global<Account>,
+        ) {
+            assert!(
+                !self.applications.get(&account).expect("Application not found").is_expired(),
+                "Expired application"
             );
+        }
+
+        /* Private method to remove an application and refund or forfeit its bond.
+         * A candidate refusing the refund can't block the closing of his application: the bond
+         * is stored in the AccountLocker for him to claim and a BondStoredEvent is emitted.
+         * Input parameters:
+         * - account: the account of the candidate
+         * - refund: true to send the bond back to the candidate, false to deposit it in the DAO
+         *   treasury
+         */
+        fn close_application(
+            &mut self,
+            account: Global<Account>,
+            refund: bool,
+        ) {
+            let application = self.applications.remove(&account).expect("Application not found");
+            if application.bond() == Decimal::ZERO {
+                return;
+            }
+
+            let bond_bucket = self.application_bonds.take(application.bond());
+            if refund {
+                if let Some(bond_bucket) = self.deposit_or_refund(account, bond_bucket) {
+                    self.account_locker.store(account, bond_bucket, false);
+                    application.emit_bond_stored_event(account);
+                }
+            } else {
+                self.account_badge.authorize_with_non_fungibles(
+                    &self.account_badge.non_fungible_local_ids(1),
+                    || self.account.deposit(bond_bucket)
+                );
+            }
+        }
+
+        /* A member can invoke this method to create/sign an operation to approve a membership
+         * application: the new member badge is sent to the candidate together with the bond.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - account: the account of the candidate
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn approve_application(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            account: Global<Account>,
+        ) -> Hash {
 
             // Get the id of the signing member badge
             let badge_id = self.get_badge_id(member_badge_proof);
@@ -462,262 +3483,421 @@ mod dao_wallet {
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::EnableBadge,
-                None,
-                None,
-                Some(vec![non_fungible_id.clone()]),
-                None,
-                None,
-                None,
+                OperationDetails::ApproveApplication(account),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
-
-                // If enough members signed the operation, enable the specified member badge
-                Member::enable(
-                    &non_fungible_id,
-                    true,
-                    &self.member_badges_resource_manager,
-                );
-            }
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
         }
 
-        /* A member can invoke this method to create/sign an operation to increase the minimum
-         * number of cosigners for future operations.
+        /* A member can invoke this method to create/sign an operation to reject a membership
+         * application: the bond is sent back to the candidate or to the DAO treasury according
+         * to the forfeit_bonds setting.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
          * - description: human readable description of the operation
+         * - account: the account of the candidate
+         * Outputs:
+         * - the hash of the operation
          */
-        pub fn increase_min_cosigners(
+        pub fn reject_application(
             &mut self,
             member_badge_proof: Proof,
             description: String,
-        ) {
-
-            // Make sure that enough members exist to sign future operatons after the threshold increase
-            let enabled_members = Member::count_enabled_members(&self.member_badges_resource_manager);
-            assert!(
-                enabled_members > self.min_cosigners,
-                "Not enough enabled members"
-            );
+            account: Global<Account>,
+        ) -> Hash {
 
-            // Get the id of the member badge
+            // Get the id of the signing member badge
             let badge_id = self.get_badge_id(member_badge_proof);
 
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::IncreaseMinCosigners,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
+                OperationDetails::RejectApplication(account),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
 
-                // If enough members signed the operation, increase the number of required
-                // cosigners
-                self.min_cosigners += 1;
-            }
+        /* Anyone can invoke this method to remove an expired membership application: the bond is
+         * sent back to the candidate or to the DAO treasury according to the forfeit_bonds
+         * setting.
+         * Input parameters:
+         * - account: the account of the candidate
+         */
+        pub fn expire_application(
+            &mut self,
+            account: Global<Account>,
+        ) {
+            assert!(
+                self.applications.get(&account).expect("Application not found").is_expired(),
+                "Application not expired yet"
+            );
+
+            self.close_application(account, !self.forfeit_bonds);
         }
 
-        /* A member can invoke this method to create/sign an operation to decrease the minimum
-         * number of cosigners for future operations.
+        /* A member can invoke this method to pay his membership dues; the payment goes to the DAO
+         * treasury and extends the date the dues are paid until by one period for each dues
+         * amount paid.
+         * A disabled member badge can pay its dues too, an EnableBadge operation is then needed
+         * to enable it again.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
+         * - payment: bucket of the dues resource
+         * Outputs:
+         * - the part of the payment exceeding a multiple of the dues amount
+         * - the new date the dues are paid until
          */
-        pub fn decrease_min_cosigners(
+        pub fn pay_dues(
             &mut self,
             member_badge_proof: Proof,
-            description: String,
-        ) {
+            payment: Bucket,
+        ) -> (Bucket, Instant) {
+
+            // Get the member badge id without checking whether it is enabled
+            let badge_id = member_badge_proof.check_with_message(
+                self.member_badges_resource_manager.address(),
+                "Incorrect proof",
+            )
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            self.pay_dues_of(badge_id, payment)
+        }
+
+        /* A member can invoke this method to pay his membership dues without passing a proof:
+         * the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * A disabled member badge can pay its dues too.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - payment: bucket of the dues resource
+         * Outputs:
+         * - the part of the payment exceeding a multiple of the dues amount
+         * - the new date the dues are paid until
+         */
+        pub fn pay_dues_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            payment: Bucket,
+        ) -> (Bucket, Instant) {
+
+            // Verify that the member badge is in the auth zone without checking whether it is
+            // enabled
+            self.assert_badge_in_auth_zone(&badge_id);
+
+            self.pay_dues_of(badge_id, payment)
+        }
+
+        /* Private method to pay the membership dues of a member.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * - payment: bucket of the dues resource
+         * Outputs:
+         * - the part of the payment exceeding a multiple of the dues amount
+         * - the new date the dues are paid until
+         */
+        fn pay_dues_of(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            mut payment: Bucket,
+        ) -> (Bucket, Instant) {
+            let (dues_resource, dues_amount, dues_period) = self.dues.expect("No dues");
+
+            // Verify the payment
+            assert!(
+                payment.resource_address() == dues_resource,
+                "Wrong dues resource"
+            );
+            let periods = i64::try_from(
+                (payment.amount() / dues_amount).checked_floor().unwrap()
+            ).unwrap();
+            assert!(
+                periods > 0,
+                "Insufficient payment"
+            );
+
+            // Put the dues in the DAO treasury
+            let dues_bucket = payment.take(dues_amount * periods);
+            self.account_badge.authorize_with_non_fungibles(
+                &self.account_badge.non_fungible_local_ids(1),
+                || self.account.deposit(dues_bucket)
+            );
+
+            // Extend the date the dues are paid until
+            let dues_paid_until = Member::pay_dues(
+                &badge_id,
+                dues_period * periods,
+                &self.member_badges_resource_manager,
+            );
+
+            (payment, dues_paid_until)
+        }
 
-            // Do not allow the number of cosigners be 1 or less
+        /* Anyone can invoke this method to disable the member badges whose dues are overdue
+         * beyond the grace period.
+         * A MemberOverdueEvent is emitted for each disabled badge; as in the DisableBadge
+         * operation, badges are not disabled if this would leave less than min_cosigners enabled
+         * member badges.
+         * Outputs:
+         * - the list of the disabled member badges
+         */
+        pub fn disable_overdue_members(&mut self) -> Vec<NonFungibleLocalId> {
             assert!(
-                self.min_cosigners > 2,
-                "Remaining cosigners must be more than 1"
+                self.dues.is_some(),
+                "No dues"
             );
 
+            Member::disable_overdue_members(
+                &self.member_badges_resource_manager,
+                self.dues_grace_period,
+                self.min_cosigners,
+                self.probation_period,
+                self.founding_members,
+            )
+        }
+
+        /* A member can invoke this method to register one more of his accounts; his signature on
+         * operations benefiting this account will be handled as a conflict of interest.
+         * Registered accounts can't be removed.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - account: the account to register; the transaction must satisfy its owner role
+         */
+        pub fn register_account(
+            &mut self,
+            member_badge_proof: Proof,
+            account: Global<Account>,
+        ) {
+
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
 
-            // Create the operation
-            let operation = Operation::new(
-                description.clone(),
-                OperationType::DecreaseMinCosigners,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-            );
+            self.register_account_of(badge_id, account);
+        }
 
-            // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+        /* A member can invoke this method to register one more of his accounts without passing a
+         * proof: the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - account: the account to register; the transaction must satisfy its owner role
+         */
+        pub fn register_account_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            account: Global<Account>,
+        ) {
 
-                // If enough members signed the operation, decrease the number of required
-                // cosigners
-                self.min_cosigners -= 1;
-            }
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
+
+            self.register_account_of(badge_id, account);
         }
 
-        /* A member can invoke this method to create/sign an operation to send a bucket of
-         * fungibles to an account.
+        /* Private method to register one more account of a member.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * - account: the account to register; the transaction must satisfy its owner role
+         */
+        fn register_account_of(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            account: Global<Account>,
+        ) {
+
+            // Make sure that the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            Member::register_account(
+                &badge_id,
+                account,
+                &self.member_badges_resource_manager,
+            );
+        }
+
+        /* A member can invoke this method to create/sign an operation to assign a member badge a
+         * budget of a fungible resource it can spend in each period without the signatures of
+         * the other members; a new budget replaces the existing one for the same resource.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
          * - description: human readable description of the operation
-         * - resource: resource address of the fungibles to send
-         * - amount: the amount of fungibles to send
-         * - recipient: the account address to send the fungibles to
+         * - non_fungible_id: the id of the member badge to assign the budget to
+         * - resource: the resource address of the budget
+         * - amount: amount that can be spent in each period; zero to revoke the budget
+         * - period: duration of a period in seconds
+         * - expiration: the budget can't be used after this date
+         * Outputs:
+         * - the hash of the operation
          */
-        pub fn send_fungibles(
+        pub fn grant_budget(
             &mut self,
             member_badge_proof: Proof,
             description: String,
+            non_fungible_id: NonFungibleLocalId,
             resource: ResourceAddress,
             amount: Decimal,
-            mut recipient: Global<Account>,
-        ) {
+            period: i64,
+            expiration: Instant,
+        ) -> Hash {
 
-            // Verify that the operation is possible
-            self.check_availability(
-                resource,
-                Some(amount),
-                None,
-            );
-
-            // Get the id of the member badge
+            // Get the id of the signing member badge
             let badge_id = self.get_badge_id(member_badge_proof);
 
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::SendFungibles,
-                Some(resource),
-                Some(amount),
-                None,
-                Some(recipient),
-                None,
-                None,
+                OperationDetails::GrantBudget(non_fungible_id.clone(), resource, amount, period, expiration),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
-
-                // If enough members signed the operation, use the account badge to withdraw
-                // the specified fungibles from the DAO treasury and put them in a bucket
-                let coin_bucket = self.account_badge.authorize_with_non_fungibles(
-                    &self.account_badge.non_fungible_local_ids(1),
-                    || self.account.withdraw(
-                        resource,
-                        amount
-                    )
-                );
-
-                // Try to send the bucket to the specified account
-                recipient.try_deposit_or_abort(
-                    coin_bucket,
-                    None
-                );
-            }
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
         }
 
-        /* A member can invoke this method to create/sign an operation to send a bucket of
-         * non fungibles to an account.
+        /* A member can invoke this method to spend part of his budget without the signatures of
+         * the other members; a BudgetSpentEvent is emitted.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - resource: resource address of the non fungibles to send
-         * - non_fungible_ids: the list of non fungible ids to send
-         * - recipient: the account address to send the non fungibles to
+         * - resource: the resource address of the budget
+         * - amount: the amount to send
+         * - recipient: the account address to send the fungibles to
          */
-        pub fn send_non_fungibles(
+        pub fn spend_budget(
             &mut self,
             member_badge_proof: Proof,
-            description: String,
             resource: ResourceAddress,
-            non_fungible_ids: Vec<NonFungibleLocalId>,
-            mut recipient: Global<Account>,
+            amount: Decimal,
+            recipient: Global<Account>,
+        ) {
+
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
+
+            self.spend_budget_of(badge_id, resource, amount, recipient);
+        }
+
+        /* A member can invoke this method to spend part of his budget without passing a proof:
+         * the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - resource: the resource address of the budget
+         * - amount: the amount to send
+         * - recipient: the account address to send the fungibles to
+         */
+        pub fn spend_budget_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            resource: ResourceAddress,
+            amount: Decimal,
+            recipient: Global<Account>,
+        ) {
+
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
+
+            self.spend_budget_of(badge_id, resource, amount, recipient);
+        }
+
+        /* Private method to spend part of the budget of a member and emit a BudgetSpentEvent.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * - resource: the resource address of the budget
+         * - amount: the amount to send
+         * - recipient: the account address to send the fungibles to
+         */
+        fn spend_budget_of(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            resource: ResourceAddress,
+            amount: Decimal,
+            recipient: Global<Account>,
         ) {
 
             // Verify that the operation is possible
             self.check_availability(
                 resource,
+                Some(amount),
                 None,
-                Some(&non_fungible_ids),
             );
 
+            // Register the expense in the member budget
+            self.budgets.get_mut(&(badge_id.clone(), resource))
+                .expect("No budget")
+                .spend(badge_id, resource, amount, recipient);
+
+            // Use the account badge to withdraw the fungibles from the DAO treasury and send
+            // them to the recipient
+            let coin_bucket = self.account_badge.authorize_with_non_fungibles(
+                &self.account_badge.non_fungible_local_ids(1),
+                || self.account.withdraw(
+                    resource,
+                    amount
+                )
+            );
+            self.deposit_or_abort(recipient, coin_bucket);
+        }
+
+        /* A member can invoke this method to create/sign an operation to mint an allowance badge
+         * and send it to an account or a component; the holder of the badge can withdraw up to
+         * amount of the resource from the DAO treasury in each period.
+         * A component receives the badge through its deposit_allowance_badge method.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - holder: the account or component to send the allowance badge to
+         * - resource: the resource address that can be withdrawn
+         * - amount: amount that can be withdrawn in each period
+         * - period: duration of a period in seconds
+         * - expiration: the allowance can't be used after this date
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn grant_allowance(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            holder: Global<AnyComponent>,
+            resource: ResourceAddress,
+            amount: Decimal,
+            period: i64,
+            expiration: Instant,
+        ) -> Hash {
+
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
 
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::SendNonFungibles,
-                Some(resource),
-                None,
-                Some(non_fungible_ids.clone()),
-                Some(recipient),
-                None,
-                None,
+                OperationDetails::GrantAllowance(holder, resource, amount, period, expiration),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
-
-                // If enough members signed the operation, use the account badge to withdraw
-                // the specified non fungibles from the DAO treasury and put them in a bucket
-                let non_fungibles_bucket = self.account_badge.authorize_with_non_fungibles(
-                    &self.account_badge.non_fungible_local_ids(1),
-                    || self.account.withdraw_non_fungibles(
-                        resource,
-                        non_fungible_ids
-                    )
-                );
-
-                // Try to send the bucket to the specified account
-                recipient.try_deposit_or_abort(
-                    non_fungibles_bucket.into(),
-                    None
-                );
-            }
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
         }
 
-        /* A member can invoke this method to remove his signature from an operation.
+        /* A member can invoke this method to create/sign an operation to revoke an allowance; the
+         * allowance badge stays with its holder but can no longer be used.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
          * - description: human readable description of the operation
-         * - operation_type: numeric identifier of the operation type
-         * - resource: the address of the resource to send or None
-         * - amount: the amount of fungibles to send of None
-         * - non_fungible_ids: the list of id of the non fungibles to send or None
-         * - recipient: address of the account to send the resources to or None
-         * - component: address of the component to send the account badge to or None
+         * - allowance_id: the id of the allowance badge to revoke
+         * Outputs:
+         * - the hash of the operation
          */
-        pub fn remove_signature(
+        pub fn revoke_allowance(
             &mut self,
             member_badge_proof: Proof,
             description: String,
-            operation_type: u8,
-            resource: Option<ResourceAddress>,
-            amount: Option<Decimal>,
-            non_fungible_ids: Option<Vec<NonFungibleLocalId>>,
-            recipient: Option<Global<Account>>,
-            component: Option<Global<AnyComponent>>,
-            validator: Option<Global<Validator>>,
-        ) {
+            allowance_id: NonFungibleLocalId,
+        ) -> Hash {
 
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
@@ -725,169 +3905,228 @@ mod dao_wallet {
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                operation_type.into(),
-                resource,
-                amount,
-                non_fungible_ids,
-                recipient,
-                component,
-                validator,
+                OperationDetails::RevokeAllowance(allowance_id.clone()),
             );
 
-            // Create the cosigner
-            let cosigner = Cosigner::new(badge_id);
+            // Register the operation in the operations KVS or add the cosigner if it's
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
 
-            // Remove the cosigner from the list for the specified operation
-            let was_present = self.operations
-                .get_mut(&operation)
-                .expect("Operation not found")
-                .swap_remove(&cosigner);
-            assert!(
-                was_present,
-                "Non existing signature"
+        /* The holder of an allowance badge can invoke this method to withdraw from the DAO
+         * treasury within the limits of the allowance; an AllowanceWithdrawnEvent is emitted.
+         * Input parameters:
+         * - allowance_badge_proof: proof of ownership of an allowance badge
+         * - amount: the amount to withdraw
+         * Outputs:
+         * - a bucket containing the withdrawn fungibles
+         */
+        pub fn withdraw_with_allowance(
+            &mut self,
+            allowance_badge_proof: Proof,
+            amount: Decimal,
+        ) -> Bucket {
+
+            // Verify the proof and get the id of the allowance badge
+            let allowance_id = allowance_badge_proof.check_with_message(
+                self.allowance_badges_resource_manager.address(),
+                "Incorrect proof",
+            )
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            // Register the withdrawal in the allowance
+            let resource = self.allowances.get_mut(&allowance_id)
+                .expect("Allowance not found or revoked")
+                .withdraw(allowance_id, amount);
+
+            // Verify that the withdrawal is possible
+            self.check_availability(
+                resource,
+                Some(amount),
+                None,
             );
+
+            // Use the account badge to withdraw the fungibles from the DAO treasury
+            self.account_badge.authorize_with_non_fungibles(
+                &self.account_badge.non_fungible_local_ids(1),
+                || self.account.withdraw(
+                    resource,
+                    amount
+                )
+            )
         }
 
-        /* A member can invoke this method to create/sign an operation to dismiss this component
-         * and send the account badge to a new one.
+        /* A member can invoke this method to delegate his signing power to another member until
+         * the specified date; each signature of the delegate will count for both member badges.
+         * A new delegation replaces the existing one.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - component: address of the component to send the account badge
+         * - delegate: the id of the member badge to delegate to
+         * - expiration: the delegation is no longer valid after this date
+         * - operation_types: numeric identifiers of the operation types the delegation applies
+         *   to or None for all of them
          */
-        pub fn transfer_account_badge(
+        pub fn delegate_signature(
             &mut self,
             member_badge_proof: Proof,
-            description: String,
-            component: Global<AnyComponent>,
+            delegate: NonFungibleLocalId,
+            expiration: Instant,
+            operation_types: Option<Vec<u8>>,
         ) {
 
-            // Verify that the operation is possible
-            assert!(
-                !self.account_badge.is_empty(),
-                "The account badge is already gone"
-            );
-
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
 
-            // Create the operation
-            let operation = Operation::new(
-                description.clone(),
-                OperationType::TransferAccountBadge,
-                None,
-                None,
-                None,
-                None,
-                Some(component),
-                None,
-            );
+            self.delegate_signature_of(badge_id, delegate, expiration, operation_types);
+        }
 
-            // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+        /* A member can invoke this method to delegate his signing power without passing a proof:
+         * the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - delegate: the id of the member badge to delegate to
+         * - expiration: the delegation is no longer valid after this date
+         * - operation_types: numeric identifiers of the operation types the delegation applies
+         *   to or None for all of them
+         */
+        pub fn delegate_signature_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            delegate: NonFungibleLocalId,
+            expiration: Instant,
+            operation_types: Option<Vec<u8>>,
+        ) {
 
-                // If enough members signed the operation, put the account badge in a bucket
-                let account_badge_bucket = self.account_badge.take_all();
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
 
-                // Deposit the bucket in the specified component
-                component.call_ignore_rtn::<(NonFungibleBucket, )>(
-                    "deposit_account_badge",
-                    &(account_badge_bucket, )
-                );
-            }
+            self.delegate_signature_of(badge_id, delegate, expiration, operation_types);
         }
 
-        /* A member can invoke this method to create/sign an operation to stake some XRD to a
-         * validator.
+        /* Private method to delegate the signing power of a member to another member.
          * Input parameters:
-         * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - amount: the XRD amount to stake
-         * - validator: address of the validator to stake to
+         * - badge_id: the non fungible id of the delegating member badge
+         * - delegate: the id of the member badge to delegate to
+         * - expiration: the delegation is no longer valid after this date
+         * - operation_types: numeric identifiers of the operation types the delegation applies
+         *   to or None for all of them
          */
-        pub fn stake(
+        fn delegate_signature_of(
             &mut self,
-            member_badge_proof: Proof,
-            description: String,
-            amount: Decimal,
-            mut validator: Global<Validator>,
+            badge_id: NonFungibleLocalId,
+            delegate: NonFungibleLocalId,
+            expiration: Instant,
+            operation_types: Option<Vec<u8>>,
         ) {
 
-            // Verify that the operation is possible
-            self.check_availability(
-                XRD,
-                Some(amount),
-                None,
+            // Verify that the delegation makes sense
+            assert!(
+                delegate != badge_id,
+                "You can't delegate to yourself"
             );
             assert!(
-                validator.accepts_delegated_stake(),
-                "This validator doesn't accept user stake"
+                self.member_badges_resource_manager.non_fungible_exists(&delegate) &&
+                Member::is_badge_enabled(&delegate, &self.member_badges_resource_manager),
+                "The delegate must be an enabled member"
+            );
+            assert!(
+                Clock::current_time_is_strictly_before(expiration, TimePrecision::Second),
+                "The delegation is already expired"
             );
 
-            // Get the id of the member badge
-            let badge_id = self.get_badge_id(member_badge_proof);
+            // Record the activity of the member
+            Member::update_last_active(&badge_id, &self.member_badges_resource_manager);
 
-            // Create the operation
-            let operation = Operation::new(
-                description.clone(),
-                OperationType::Stake,
-                None,
-                Some(amount),
-                None,
-                None,
-                None,
-                Some(validator),
+            // Store the delegation in the member badge
+            Member::set_delegation(
+                &badge_id,
+                Some(
+                    Delegation::new(
+                        delegate,
+                        expiration,
+                        operation_types.map(
+                            |operation_types| operation_types.into_iter()
+                                .map(|operation_type| operation_type.into())
+                                .collect()
+                        ),
+                    )
+                ),
+                &self.member_badges_resource_manager,
             );
+        }
 
-            // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+        /* A member can invoke this method to revoke the delegation of his signing power; the
+         * signatures already added on his behalf are not removed.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         */
+        pub fn revoke_delegation(
+            &mut self,
+            member_badge_proof: Proof,
+        ) {
 
-                // If enough members signed the operation, use the account badge to withdraw
-                // the XRD from the DAO treasury, stake them and put the LSU back into the account
-                self.account_badge.authorize_with_non_fungibles(
-                    &self.account_badge.non_fungible_local_ids(1),
-                    || {
-                        let xrd_bucket = self.account.withdraw(
-                            XRD,
-                            amount,
-                        );
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
 
-                        let lsu_bucket = validator.stake(FungibleBucket(xrd_bucket));
+            self.revoke_delegation_of(badge_id);
+        }
 
-                        self.account.deposit(lsu_bucket.into());
-                    }
-                );
-            }
+        /* A member can invoke this method to revoke the delegation of his signing power without
+         * passing a proof: the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         */
+        pub fn revoke_delegation_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+        ) {
+
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
+
+            self.revoke_delegation_of(badge_id);
         }
 
-        /* A member can invoke this method to create/sign an operation to start the unstake of
-         * some XRD from a validator.
+        /* Private method to revoke the delegation of the signing power of a member.
          * Input parameters:
-         * - member_badge_proof: proof of ownership of a member badge
-         * - description: human readable description of the operation
-         * - amount: the LSU amount to unstake
-         * - validator: address of the validator to unstake from
+         * - badge_id: the non fungible id of the member badge
          */
-        pub fn unstake(
+        fn revoke_delegation_of(
             &mut self,
-            member_badge_proof: Proof,
-            description: String,
-            amount: Decimal,
-            mut validator: Global<Validator>,
+            badge_id: NonFungibleLocalId,
         ) {
 
-            // Find the lsu resource address for the specified validator
-            let lsu_global_address: GlobalAddress = validator.get_metadata("pool_unit").ok().unwrap().unwrap();
-            let lsu_address = ResourceAddress::try_from(lsu_global_address).unwrap();
+            // Record the activity of the member
+            Member::update_last_active(&badge_id, &self.member_badges_resource_manager);
 
-            // Verify that the operation is possible
-            self.check_availability(
-                lsu_address,
-                Some(amount),
+            // Remove the delegation from the member badge
+            Member::set_delegation(
+                &badge_id,
                 None,
+                &self.member_badges_resource_manager,
             );
+        }
+
+        /* A member can invoke this method to create/sign an operation to mint a member badge for
+         * a keyless member, identified by the virtual signature badge of his public key.
+         * The new member badge is kept by the component; the keyless member signs operations
+         * through sign_operation_with_auth_zone in transactions signed with his key.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - description: human readable description of the operation
+         * - signer: the virtual signature badge of the public key of the new member
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn mint_keyless_member_badge(
+            &mut self,
+            member_badge_proof: Proof,
+            description: String,
+            signer: NonFungibleGlobalId,
+        ) -> Hash {
 
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
@@ -895,65 +4134,205 @@ mod dao_wallet {
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::Unstake,
-                None,
-                Some(amount),
-                None,
-                None,
-                None,
-                Some(validator),
+                OperationDetails::MintKeylessBadge(signer.clone()),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
+        }
 
-                // If enough members signed the operation, use the account badge to withdraw
-                // the LSU from the DAO treasury, unstake them and put the claim NFT back into
-                // the account
-                self.account_badge.authorize_with_non_fungibles(
-                    &self.account_badge.non_fungible_local_ids(1),
-                    || {
-                        let lsu_bucket = self.account.withdraw(
-                            lsu_address,
-                            amount,
-                        );
+        /* A member can invoke this method to register the BLS12-381 public key he uses to sign
+         * operations off-ledger; a new key replaces the existing one.
+         * Input parameters:
+         * - member_badge_proof: proof of ownership of a member badge
+         * - public_key: the BLS public key
+         * - proof_of_possession: signature of the bytes of the public key with the matching
+         *   private key
+         */
+        pub fn register_bls_key(
+            &mut self,
+            member_badge_proof: Proof,
+            public_key: Bls12381G1PublicKey,
+            proof_of_possession: Bls12381G2Signature,
+        ) {
 
-                        let claim_nft_bucket = validator.unstake(FungibleBucket(lsu_bucket));
+            // Get the id of the member badge
+            let badge_id = self.get_badge_id(member_badge_proof);
 
-                        self.account.deposit(claim_nft_bucket.into());
-                    }
+            self.register_bls_key_of(badge_id, public_key, proof_of_possession);
+        }
+
+        /* A member can invoke this method to register his BLS12-381 public key without passing a
+         * proof: the member badge is looked for in the auth zone of the transaction.
+         * Keyless members don't need any proof, the transaction must be signed with their keys.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge in the auth zone
+         * - public_key: the BLS public key
+         * - proof_of_possession: signature of the bytes of the public key with the matching
+         *   private key
+         */
+        pub fn register_bls_key_with_auth_zone(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            public_key: Bls12381G1PublicKey,
+            proof_of_possession: Bls12381G2Signature,
+        ) {
+
+            // Verify that the member badge is in the auth zone
+            let badge_id = self.get_badge_ids_from_auth_zone(vec![badge_id]).remove(0);
+
+            self.register_bls_key_of(badge_id, public_key, proof_of_possession);
+        }
+
+        /* Private method to register the BLS12-381 public key of a member.
+         * Input parameters:
+         * - badge_id: the non fungible id of the member badge
+         * - public_key: the BLS public key
+         * - proof_of_possession: signature of the bytes of the public key with the matching
+         *   private key
+         */
+        fn register_bls_key_of(
+            &mut self,
+            badge_id: NonFungibleLocalId,
+            public_key: Bls12381G1PublicKey,
+            proof_of_possession: Bls12381G2Signature,
+        ) {
+
+            // Make sure that the member owns the private key, this prevents rogue key attacks on
+            // aggregate signatures
+            assert!(
+                CryptoUtils::bls12381_v1_verify(
+                    public_key.0,
+                    public_key,
+                    proof_of_possession,
+                ),
+                "Invalid proof of possession"
+            );
+
+            self.bls_keys.insert(badge_id, public_key);
+        }
+
+        /* Anyone can invoke this method to get the canonical hash of an operation.
+         * Input parameters:
+         * - description: human readable description of the operation
+         * - details: the operation type and its parameters
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn get_operation_hash(
+            &self,
+            description: String,
+            details: OperationDetails,
+        ) -> Hash {
+            Operation::new(
+                description,
+                details,
+            ).hash()
+        }
+
+        /* Anyone can invoke this method to get the message the members have to sign off-ledger
+         * with their BLS keys to approve an operation; it depends on this component and on the
+         * nonce of the operation, which is increased each time the operation is executed or a
+         * signature is removed from it, so that a signature can't be replayed.
+         * Input parameters:
+         * - operation_hash: the hash of an operation that has already been created
+         * Outputs:
+         * - the message to sign
+         */
+        pub fn get_bls_message(
+            &self,
+            operation_hash: Hash,
+        ) -> Hash {
+            let nonce = self.operation_hashes.get(&operation_hash)
+                .expect("Operation not found")
+                .1;
+
+            CryptoUtils::blake2b_256_hash(
+                scrypto_encode(
+                    &(Runtime::global_address(), operation_hash, nonce)
+                ).unwrap()
+            )
+        }
+
+        /* Anyone can invoke this method to submit the aggregate BLS signature of one or more
+         * members approving an operation off-ledger; the members are added as cosigners and the
+         * operation is executed as soon as enough members signed it.
+         * Input parameters:
+         * - operation_hash: the hash of an operation that has already been created
+         * - signers: the non fungible ids of the member badges of the signers
+         * - aggregate_signature: the aggregate of the signatures of the message returned by
+         *   get_bls_message
+         * Outputs:
+         * - the hash of the operation
+         */
+        pub fn execute_with_signatures(
+            &mut self,
+            operation_hash: Hash,
+            signers: Vec<NonFungibleLocalId>,
+            aggregate_signature: Bls12381G2Signature,
+        ) -> Hash {
+            let operation = self.operation_hashes.get(&operation_hash)
+                .expect("Operation not found")
+                .0
+                .clone();
+
+            // Verify that the signers are distinct enabled members and get their keys
+            let mut badge_ids: Vec<NonFungibleLocalId> = vec![];
+            let mut public_keys: Vec<Bls12381G1PublicKey> = vec![];
+            for badge_id in signers.into_iter() {
+                let member = self.member_badges_resource_manager
+                    .get_non_fungible_data::<Member>(&badge_id);
+                assert!(
+                    !member.is_expired(),
+                    "Expired badge"
                 );
+                assert!(
+                    member.is_enabled(),
+                    "Disabled badge"
+                );
+
+                if !badge_ids.contains(&badge_id) {
+                    public_keys.push(
+                        *self.bls_keys.get(&badge_id).expect("BLS key not registered")
+                    );
+                    badge_ids.push(badge_id);
+                }
             }
+            assert!(
+                !badge_ids.is_empty(),
+                "No member badge"
+            );
+
+            // Verify the aggregate signature
+            assert!(
+                CryptoUtils::bls12381_v1_fast_aggregate_verify(
+                    self.get_bls_message(operation_hash),
+                    public_keys,
+                    aggregate_signature,
+                ),
+                "Invalid signature"
+            );
+
+            // Add the cosigners; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, badge_ids)
         }
 
-        /* A member can invoke this method to create/sign an operation to complete the unstake of
-         * some XRD from a validator.
+        /* A member can invoke this method to create/sign a Batch operation: an ordered list of
+         * actions that members approve at once and that are executed all together or not at all.
          * Input parameters:
          * - member_badge_proof: proof of ownership of a member badge
          * - description: human readable description of the operation
-         * - non_fungible_ids: ids of the NFT to claim
-         * - validator: address of the validator to unstake from
+         * - actions: the ordered list of actions to execute
+         * Outputs:
+         * - the hash of the operation
          */
-        pub fn claim_unstaked_xrd(
+        pub fn batch(
             &mut self,
             member_badge_proof: Proof,
             description: String,
-            non_fungible_ids: Vec<NonFungibleLocalId>,
-            mut validator: Global<Validator>,
-        ) {
-
-            // Find the claim NFT resource address for the specified validator
-            let claim_nft_global_address: GlobalAddress = validator.get_metadata("claim_nft").ok().unwrap().unwrap();
-            let claim_nft_address = ResourceAddress::try_from(claim_nft_global_address).unwrap();
-
-            // Verify that the operation is possible
-            self.check_availability(
-                claim_nft_address,
-                None,
-                Some(&non_fungible_ids),
-            );
-            //TODO: check claim epoch?
+            actions: Vec<Action>,
+        ) -> Hash {
 
             // Get the id of the member badge
             let badge_id = self.get_badge_id(member_badge_proof);
@@ -961,36 +4340,12 @@ mod dao_wallet {
             // Create the operation
             let operation = Operation::new(
                 description.clone(),
-                OperationType::ClaimUnstakedXrd,
-                None,
-                None,
-                Some(non_fungible_ids.clone()),
-                None,
-                None,
-                Some(validator),
+                OperationDetails::Batch(actions),
             );
 
             // Register the operation in the operations KVS or add the cosigner if it's
-            // already there
-            if self.add_cosigner(operation, badge_id) {
-
-                // If enough members signed the operation, use the account badge to withdraw
-                // the Claim NFT from the DAO treasury, claim the XRD and put them back into
-                // the account
-                self.account_badge.authorize_with_non_fungibles(
-                    &self.account_badge.non_fungible_local_ids(1),
-                    || {
-                        let claim_nft_bucket = self.account.withdraw_non_fungibles(
-                            claim_nft_address,
-                            non_fungible_ids,
-                        );
-
-                        let xrd_bucket = validator.claim_xrd(claim_nft_bucket);
-
-                        self.account.deposit(xrd_bucket.into());
-                    }
-                );
-            }
+            // already there; the operation is executed as soon as enough members signed it
+            self.add_cosigners(operation, vec![badge_id])
         }
     }
 }
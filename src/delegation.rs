@@ -0,0 +1,62 @@
+use scrypto::prelude::*;
+use crate::operation::*;
+
+/* A Delegation lets a member on leave transfer his signing power to another member until a
+ * given date; a signature by the delegate counts for both member badges.
+ */
+#[derive(ScryptoSbor, Clone)]
+pub struct Delegation {
+
+    // non fungible id of the member badge signing on behalf of the delegator
+    delegate: NonFungibleLocalId,
+
+    // the delegation is no longer valid after this date
+    expiration: Instant,
+
+    // the operation types the delegation applies to or None for all of them
+    operation_types: Option<Vec<OperationType>>,
+}
+
+impl Delegation {
+
+    /* Create a new Delegation struct.
+     * Input parameters:
+     * - delegate: non fungible id of the member badge signing on behalf of the delegator
+     * - expiration: the delegation is no longer valid after this date
+     * - operation_types: the operation types the delegation applies to or None for all of them
+     * Outputs:
+     * - a Delegation object
+     */
+    pub fn new(
+        delegate: NonFungibleLocalId,
+        expiration: Instant,
+        operation_types: Option<Vec<OperationType>>,
+    ) -> Delegation {
+        Delegation {
+            delegate,
+            expiration,
+            operation_types,
+        }
+    }
+
+    /* Check whether a signature by the specified member badge on an operation of the specified
+     * type counts for the delegator too.
+     * Input parameters:
+     * - delegate: non fungible id of the member badge signing the operation
+     * - operation_type: the type of the signed operation
+     * Outputs:
+     * - true if the delegation is valid now and applies to this member and operation type
+     */
+    pub fn covers(
+        &self,
+        delegate: &NonFungibleLocalId,
+        operation_type: OperationType,
+    ) -> bool {
+        self.delegate == *delegate &&
+            Clock::current_time_is_strictly_before(self.expiration, TimePrecision::Second) &&
+            match &self.operation_types {
+                None => true,
+                Some(operation_types) => operation_types.contains(&operation_type),
+            }
+    }
+}
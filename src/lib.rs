@@ -1,4 +1,15 @@
 mod cosigner;
 mod operation;
 mod member;
+mod application;
+mod budget;
+mod allowance;
+mod delegation;
+mod action;
+mod resource_action;
+mod new_resource;
+mod operation_details;
+// The stubs the #[blueprint] macro generates for new, swap, grant_budget and grant_allowance
+// take as many arguments as the methods and can't be annotated, so this lint is allowed here
+#[allow(clippy::too_many_arguments)]
 pub mod dao_wallet;
@@ -1,4 +1,6 @@
 use scrypto::prelude::*;
+use crate::operation::*;
+use crate::delegation::*;
 
 /* A Member struct is the non fungible data of the member badges
  */
@@ -11,27 +13,202 @@ pub struct Member {
 
     // the date the member badge was minted
     creation_date: Instant,
+
+    // the end of the member term or None if the member badge never expires
+    #[mutable]
+    valid_until: Option<Instant>,
+
+    // the last time the member signed or unsigned an operation
+    #[mutable]
+    last_active: Instant,
+
+    // the date the dues of the member are paid until
+    #[mutable]
+    dues_paid_until: Instant,
+
+    // the accounts of the member; his signature on operations benefiting these accounts is a
+    // conflict of interest
+    #[mutable]
+    accounts: Vec<Global<Account>>,
+
+    // the member badge this member delegated his signing power to, or None
+    #[mutable]
+    delegation: Option<Delegation>,
+
+    // the virtual signature badge of a keyless member, or None if the member holds his badge in
+    // an account
+    signer: Option<NonFungibleGlobalId>,
+}
+
+/* This event is emitted when an expired member badge is disabled by the expire_members method.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MemberExpiredEvent {
+    badge_id: NonFungibleLocalId,
+    valid_until: Instant,
+}
+
+/* This event is emitted when a member badge is disabled by the disable_overdue_members method.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MemberOverdueEvent {
+    badge_id: NonFungibleLocalId,
+    dues_paid_until: Instant,
 }
 
 impl Member {
 
     /* Create a new Member struct.
+     * Input parameters:
+     * - term: duration in seconds of the member term or None if the badge never expires
+     * - account: the account the member badge is sent to
+     * Outputs:
+     * - a Member object
+     */
+    pub fn new(
+        term: Option<i64>,
+        account: Global<Account>,
+    ) -> Member {
+        let creation_date = Clock::current_time_rounded_to_seconds();
+
+        Member {
+            enabled: true,
+            creation_date,
+            valid_until: term.map(
+                |term| creation_date.add_seconds(term).unwrap()
+            ),
+            last_active: creation_date,
+            dues_paid_until: creation_date,
+            accounts: vec![account],
+            delegation: None,
+            signer: None,
+        }
+    }
+
+    /* Create a new Member struct for a keyless member, identified by the virtual signature badge
+     * of his public key instead of a badge in an account.
+     * Input parameters:
+     * - term: duration in seconds of the member term or None if the badge never expires
+     * - signer: the virtual signature badge of the member
      * Outputs:
      * - a Member object
      */
-    pub fn new() -> Member {
+    pub fn new_keyless(
+        term: Option<i64>,
+        signer: NonFungibleGlobalId,
+    ) -> Member {
+        let creation_date = Clock::current_time_rounded_to_seconds();
+
         Member {
             enabled: true,
-            creation_date: Clock::current_time_rounded_to_seconds(),
+            creation_date,
+            valid_until: term.map(
+                |term| creation_date.add_seconds(term).unwrap()
+            ),
+            last_active: creation_date,
+            dues_paid_until: creation_date,
+            accounts: vec![],
+            delegation: None,
+            signer: Some(signer),
         }
     }
 
-    /* Check whether this member badge is enabled or not.
+    /* Check whether this member badge is enabled and not expired.
      * Outputs:
-     * - true if the member badge is currently enabled
+     * - true if the member badge can currently be used
      */
     pub fn is_enabled(&self) -> bool {
-        self.enabled
+        self.enabled && !self.is_expired()
+    }
+
+    /* Check whether the term of this member badge is over.
+     * Outputs:
+     * - true if the member badge has a valid_until date and it's in the past
+     */
+    pub fn is_expired(&self) -> bool {
+        match self.valid_until {
+            None => false,
+            Some(valid_until) => Clock::current_time_is_at_or_after(
+                valid_until,
+                TimePrecision::Second,
+            ),
+        }
+    }
+
+    /* Get the virtual signature badge of this member if he's a keyless member.
+     * Outputs:
+     * - the virtual signature badge or None
+     */
+    pub fn signer(&self) -> Option<NonFungibleGlobalId> {
+        self.signer.clone()
+    }
+
+    /* Get the accounts registered for this member badge.
+     * Outputs:
+     * - the list of the accounts of the member
+     */
+    pub fn accounts(&self) -> &Vec<Global<Account>> {
+        &self.accounts
+    }
+
+    /* Check whether this member badge is enabled and has not signed or unsigned any operation for
+     * longer than the specified period.
+     * Input parameters:
+     * - inactivity_period: number of seconds without activity
+     * Outputs:
+     * - true if the member badge is inactive
+     */
+    pub fn is_inactive(
+        &self,
+        inactivity_period: i64,
+    ) -> bool {
+        self.is_enabled() &&
+            Clock::current_time_is_strictly_after(
+                self.last_active.add_seconds(inactivity_period).unwrap(),
+                TimePrecision::Second,
+            )
+    }
+
+    /* Check whether the dues of this member are unpaid for longer than the grace period.
+     * Input parameters:
+     * - grace_period: number of seconds the dues can stay unpaid
+     * Outputs:
+     * - true if the dues are overdue beyond the grace period
+     */
+    pub fn is_overdue(
+        &self,
+        grace_period: i64,
+    ) -> bool {
+        Clock::current_time_is_strictly_after(
+            self.dues_paid_until.add_seconds(grace_period).unwrap(),
+            TimePrecision::Second,
+        )
+    }
+
+    /* Check whether this member badge is still in its probation period; a member badge in its
+     * probation period can propose operations but its signatures do not count toward the
+     * min_cosigners threshold.
+     * Member badges minted during the component instantiation are not subject to probation.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of this member badge
+     * - probation_period: number of seconds since the creation date the probation lasts
+     * - founding_members: number of member badges minted during the component instantiation
+     * Outputs:
+     * - true if the member badge is in its probation period
+     */
+    pub fn is_on_probation(
+        &self,
+        non_fungible_id: &NonFungibleLocalId,
+        probation_period: i64,
+        founding_members: u64,
+    ) -> bool {
+        match non_fungible_id {
+            NonFungibleLocalId::Integer(id) if id.value() <= founding_members => false,
+            _ => Clock::current_time_is_strictly_before(
+                self.creation_date.add_seconds(probation_period).unwrap(),
+                TimePrecision::Second,
+            ),
+        }
     }
 
     /* Enable or disable a member badge.
@@ -46,20 +223,348 @@ impl Member {
         resource_manager: &NonFungibleResourceManager,
     ) {
         resource_manager.update_non_fungible_data(
-            &non_fungible_id,
+            non_fungible_id,
             "enabled",
             enabled
         );
     }
 
-    /* Count how many member badges are currently enabled.
+    /* Record that a member signed or unsigned an operation now.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of the member badge
+     * - resource_manager: the resource manager of the member badges
+     */
+    pub fn update_last_active(
+        non_fungible_id: &NonFungibleLocalId,
+        resource_manager: &NonFungibleResourceManager,
+    ) {
+        resource_manager.update_non_fungible_data(
+            non_fungible_id,
+            "last_active",
+            Clock::current_time_rounded_to_seconds(),
+        );
+    }
+
+    /* List the enabled member badges that have not signed or unsigned any operation for longer
+     * than the specified period.
+     * Input parameters:
+     * - resource_manager: the resource manager of the member badges
+     * - inactivity_period: number of seconds without activity
+     * Outputs:
+     * - the list of the inactive member badges and the last time each of them was active
+     */
+    pub fn inactive_members(
+        resource_manager: &NonFungibleResourceManager,
+        inactivity_period: i64,
+    ) -> Vec<(NonFungibleLocalId, Instant)> {
+        let mut id: u64 = 1;
+        let mut inactive_members: Vec<(NonFungibleLocalId, Instant)> = vec![];
+        loop {
+            let non_fungible_id = NonFungibleLocalId::Integer(id.into());
+            if !resource_manager.non_fungible_exists(&non_fungible_id) {
+                break;
+            }
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.is_inactive(inactivity_period) {
+                inactive_members.push((non_fungible_id, member.last_active));
+            }
+            id += 1;
+        }
+
+        inactive_members
+    }
+
+    /* Set or remove the delegation of the signing power of a member badge.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of the delegating member badge
+     * - delegation: the new delegation or None to revoke the existing one
+     * - resource_manager: the resource manager of the member badges
+     */
+    pub fn set_delegation(
+        non_fungible_id: &NonFungibleLocalId,
+        delegation: Option<Delegation>,
+        resource_manager: &NonFungibleResourceManager,
+    ) {
+        resource_manager.update_non_fungible_data(
+            non_fungible_id,
+            "delegation",
+            delegation,
+        );
+    }
+
+    /* List the enabled member badges whose signing power on the specified operation type is
+     * currently delegated to the specified member badge.
+     * Input parameters:
+     * - delegate: non fungible id of the member badge signing the operation
+     * - operation_type: the type of the signed operation
+     * - resource_manager: the resource manager of the member badges
+     * Outputs:
+     * - the list of the member badges the delegate is signing for
+     */
+    pub fn delegators(
+        delegate: &NonFungibleLocalId,
+        operation_type: OperationType,
+        resource_manager: &NonFungibleResourceManager,
+    ) -> Vec<NonFungibleLocalId> {
+        let mut id: u64 = 1;
+        let mut delegators: Vec<NonFungibleLocalId> = vec![];
+        loop {
+            let non_fungible_id = NonFungibleLocalId::Integer(id.into());
+            if !resource_manager.non_fungible_exists(&non_fungible_id) {
+                break;
+            }
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.is_enabled() &&
+                member.delegation.is_some_and(
+                    |delegation| delegation.covers(delegate, operation_type)
+                ) {
+                delegators.push(non_fungible_id);
+            }
+            id += 1;
+        }
+
+        delegators
+    }
+
+    /* Set the end of the term of a member badge.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of the member badge to renew
+     * - valid_until: the new end of the member term
+     * - resource_manager: the resource manager of the member badges
+     */
+    pub fn renew(
+        non_fungible_id: &NonFungibleLocalId,
+        valid_until: Instant,
+        resource_manager: &NonFungibleResourceManager,
+    ) {
+        resource_manager.update_non_fungible_data(
+            non_fungible_id,
+            "valid_until",
+            Some(valid_until),
+        );
+    }
+
+    /* Check whether an account is registered for any member badge.
+     * Input parameters:
+     * - resource_manager: the resource manager of the member badges
+     * - account: the account to look for
+     * Outputs:
+     * - true if the account is among the accounts of a member badge
+     */
+    pub fn is_member_account(
+        resource_manager: &NonFungibleResourceManager,
+        account: Global<Account>,
+    ) -> bool {
+        let mut id: u64 = 1;
+        loop {
+            let non_fungible_id = NonFungibleLocalId::Integer(id.into());
+            if !resource_manager.non_fungible_exists(&non_fungible_id) {
+                break;
+            }
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.accounts.contains(&account) {
+                return true;
+            }
+            id += 1;
+        }
+
+        false
+    }
+
+    /* Add an account to the list of the accounts of a member badge.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of the member badge
+     * - account: the account to register
+     * - resource_manager: the resource manager of the member badges
+     */
+    pub fn register_account(
+        non_fungible_id: &NonFungibleLocalId,
+        account: Global<Account>,
+        resource_manager: &NonFungibleResourceManager,
+    ) {
+        let mut accounts = resource_manager.get_non_fungible_data::<Member>(non_fungible_id)
+            .accounts;
+        assert!(
+            !accounts.contains(&account),
+            "Account already registered"
+        );
+        accounts.push(account);
+
+        resource_manager.update_non_fungible_data(
+            non_fungible_id,
+            "accounts",
+            accounts,
+        );
+    }
+
+    /* Extend the date the dues of a member badge are paid until.
+     * Input parameters:
+     * - non_fungible_id: non fungible id of the member badge
+     * - seconds: number of seconds the payment covers
+     * - resource_manager: the resource manager of the member badges
+     * Outputs:
+     * - the new date the dues are paid until
+     */
+    pub fn pay_dues(
+        non_fungible_id: &NonFungibleLocalId,
+        seconds: i64,
+        resource_manager: &NonFungibleResourceManager,
+    ) -> Instant {
+        let member = resource_manager.get_non_fungible_data::<Member>(non_fungible_id);
+        let dues_paid_until = member.dues_paid_until.add_seconds(seconds).unwrap();
+
+        resource_manager.update_non_fungible_data(
+            non_fungible_id,
+            "dues_paid_until",
+            dues_paid_until,
+        );
+
+        dues_paid_until
+    }
+
+    /* Disable the member badges whose dues are overdue beyond the grace period and emit a
+     * MemberOverdueEvent for each of them.
+     * As in the DisableBadge operation, a member badge is not disabled if this would leave less
+     * than min_cosigners enabled member badges not in their probation period.
+     * Input parameters:
+     * - resource_manager: the resource manager of the member badges
+     * - grace_period: number of seconds the dues can stay unpaid
+     * - min_cosigners: the minimum number of cosigners to execute an operation
+     * - probation_period: number of seconds since the creation date the probation lasts
+     * - founding_members: number of member badges minted during the component instantiation
+     * Outputs:
+     * - the list of the disabled member badges
+     */
+    pub fn disable_overdue_members(
+        resource_manager: &NonFungibleResourceManager,
+        grace_period: i64,
+        min_cosigners: usize,
+        probation_period: i64,
+        founding_members: u64,
+    ) -> Vec<NonFungibleLocalId> {
+        let mut id: u64 = 1;
+        let mut enabled_members: usize = 0;
+        let mut overdue_members: Vec<(NonFungibleLocalId, Instant, bool)> = vec![];
+        loop {
+            let non_fungible_id = NonFungibleLocalId::Integer(id.into());
+            if !resource_manager.non_fungible_exists(&non_fungible_id) {
+                break;
+            }
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.enabled {
+                let counted = member.is_enabled() &&
+                    !member.is_on_probation(&non_fungible_id, probation_period, founding_members);
+                if counted {
+                    enabled_members += 1;
+                }
+                if member.is_overdue(grace_period) {
+                    overdue_members.push((non_fungible_id, member.dues_paid_until, counted));
+                }
+            }
+            id += 1;
+        }
+
+        let mut disabled_members: Vec<NonFungibleLocalId> = vec![];
+        for (non_fungible_id, dues_paid_until, counted) in overdue_members.into_iter() {
+            if counted {
+                if enabled_members <= min_cosigners {
+                    continue;
+                }
+                enabled_members -= 1;
+            }
+
+            Member::enable(&non_fungible_id, false, resource_manager);
+
+            Runtime::emit_event(
+                MemberOverdueEvent {
+                    badge_id: non_fungible_id.clone(),
+                    dues_paid_until,
+                }
+            );
+
+            disabled_members.push(non_fungible_id);
+        }
+
+        disabled_members
+    }
+
+    /* Disable the member badges whose term is over and emit a MemberExpiredEvent for each of
+     * them.
+     * As in the DisableBadge operation, a member badge is not disabled if this would leave less
+     * than min_cosigners enabled member badges not in their probation period; an expired badge
+     * can't sign operations, so it doesn't count.
+     * Input parameters:
+     * - resource_manager: the resource manager of the member badges
+     * - min_cosigners: the minimum number of cosigners to execute an operation
+     * - probation_period: number of seconds since the creation date the probation lasts
+     * - founding_members: number of member badges minted during the component instantiation
+     * Outputs:
+     * - the list of the disabled member badges
+     */
+    pub fn disable_expired_members(
+        resource_manager: &NonFungibleResourceManager,
+        min_cosigners: usize,
+        probation_period: i64,
+        founding_members: u64,
+    ) -> Vec<NonFungibleLocalId> {
+        let mut id: u64 = 1;
+        let mut enabled_members: usize = 0;
+        let mut expired_members: Vec<(NonFungibleLocalId, Instant, bool)> = vec![];
+        loop {
+            let non_fungible_id = NonFungibleLocalId::Integer(id.into());
+            if !resource_manager.non_fungible_exists(&non_fungible_id) {
+                break;
+            }
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.enabled {
+                let counted = member.is_enabled() &&
+                    !member.is_on_probation(&non_fungible_id, probation_period, founding_members);
+                if counted {
+                    enabled_members += 1;
+                }
+                if member.is_expired() {
+                    expired_members.push((non_fungible_id, member.valid_until.unwrap(), counted));
+                }
+            }
+            id += 1;
+        }
+
+        let mut disabled_members: Vec<NonFungibleLocalId> = vec![];
+        for (non_fungible_id, valid_until, counted) in expired_members.into_iter() {
+            if counted {
+                if enabled_members <= min_cosigners {
+                    continue;
+                }
+                enabled_members -= 1;
+            }
+
+            Member::enable(&non_fungible_id, false, resource_manager);
+
+            Runtime::emit_event(
+                MemberExpiredEvent {
+                    badge_id: non_fungible_id.clone(),
+                    valid_until,
+                }
+            );
+
+            disabled_members.push(non_fungible_id);
+        }
+
+        disabled_members
+    }
+
+    /* Count how many member badges are currently enabled and not in their probation period.
      * Input parameters:
      * - resource_manager: the resource manager of the member badges
+     * - probation_period: number of seconds since the creation date the probation lasts
+     * - founding_members: number of member badges minted during the component instantiation
      * Outputs:
-     * - the number of member badges that are currently enabled
+     * - the number of member badges that are currently enabled and not in probation
      */
     pub fn count_enabled_members(
         resource_manager: &NonFungibleResourceManager,
+        probation_period: i64,
+        founding_members: u64,
     ) -> usize {
         let mut id: u64 = 1;
         let mut active_members: usize = 0;
@@ -68,13 +573,15 @@ impl Member {
             if !resource_manager.non_fungible_exists(&non_fungible_id) {
                 break;
             }
-            if resource_manager.get_non_fungible_data::<Member>(&non_fungible_id).enabled {
+            let member = resource_manager.get_non_fungible_data::<Member>(&non_fungible_id);
+            if member.is_enabled() &&
+                !member.is_on_probation(&non_fungible_id, probation_period, founding_members) {
                 active_members += 1;
             }
             id += 1;
         }
 
-        return active_members;
+        active_members
     }
 
     /* Check if the specified member badge is currently enabled.
@@ -88,6 +595,6 @@ impl Member {
         non_fungible_id: &NonFungibleLocalId,
         resource_manager: &NonFungibleResourceManager,
     ) -> bool {
-        resource_manager.get_non_fungible_data::<Member>(&non_fungible_id).enabled
+        resource_manager.get_non_fungible_data::<Member>(non_fungible_id).enabled
     }
 }
@@ -0,0 +1,35 @@
+use scrypto::prelude::*;
+
+/* A NewResource describes a resource created by a CreateResource operation; all of its roles
+ * require the DaoWallet component, so it can only be minted, burnt and updated through
+ * ManageResource operations.
+ */
+#[derive(ScryptoSbor, PartialEq, Clone)]
+pub enum NewResource {
+
+    // divisibility, initial supply to deposit in the DAO treasury and metadata of a fungible
+    // resource
+    Fungible(u8, Decimal, Vec<(String, MetadataValue)>),
+
+    // metadata of a non fungible resource with integer ids and DaoNonFungible data; there's no
+    // initial supply
+    NonFungible(Vec<(String, MetadataValue)>),
+}
+
+/* The data of the non fungibles of a resource created by a CreateResource operation.
+ */
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct DaoNonFungible {
+
+    // Name of the non fungible
+    #[mutable]
+    name: String,
+
+    // Description of the non fungible
+    #[mutable]
+    description: String,
+
+    // Image of the non fungible
+    #[mutable]
+    key_image_url: UncheckedUrl,
+}
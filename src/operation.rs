@@ -1,5 +1,9 @@
 use scrypto::prelude::*;
 use crate::cosigner::*;
+use crate::action::*;
+use crate::resource_action::*;
+use crate::new_resource::*;
+use crate::operation_details::*;
 
 /* List of possible operation types a member can sign.
  */
@@ -39,11 +43,74 @@ pub enum OperationType {
 
     // claim_unstaked_xrd method
     ClaimUnstakedXrd = 10,
+
+    // set_member_badge_metadata method
+    SetMemberBadgeMetadata = 11,
+
+    // renew_member method
+    RenewMember = 12,
+
+    // disable_inactive_members method
+    DisableInactiveMembers = 13,
+
+    // invite_member method
+    InviteMember = 14,
+
+    // revoke_invitation method
+    RevokeInvitation = 15,
+
+    // approve_application method
+    ApproveApplication = 16,
+
+    // reject_application method
+    RejectApplication = 17,
+
+    // grant_budget method
+    GrantBudget = 18,
+
+    // grant_allowance method
+    GrantAllowance = 19,
+
+    // revoke_allowance method
+    RevokeAllowance = 20,
+
+    // mint_keyless_member_badge method
+    MintKeylessBadge = 21,
+
+    // batch method
+    Batch = 22,
+
+    // send_fungibles_multi method
+    SendFungiblesMulti = 23,
+
+    // call_method method
+    CallMethod = 24,
+
+    // swap method
+    Swap = 25,
+
+    // contribute_to_pool method
+    ContributeToPool = 26,
+
+    // redeem_from_pool method
+    RedeemFromPool = 27,
+
+    // manage_resource method
+    ManageResource = 28,
+
+    // create_resource method
+    CreateResource = 29,
 }
 
+/* A MethodCall is the name of the method to call, the SBOR-encoded tuple of its arguments and
+ * the resources to withdraw from the DAO treasury and pass to the method as buckets before the
+ * other arguments.
+ */
+pub type MethodCall = (String, Vec<u8>, Vec<(ResourceAddress, ResourceSpecifier)>);
+
 /* An Operation is the internal representation of an operation a member can sign.
  */
-#[derive(ScryptoSbor, PartialEq)]
+#[derive(ScryptoSbor, PartialEq, Clone)]
 pub struct Operation {
 
     // Human readable representation of the operation
@@ -52,23 +119,64 @@ pub struct Operation {
     // Type of operation
     operation_type: OperationType,
 
-    // Resource address (SendFungibles and SendNonFungibles types) or None
+    // Resource address (SendFungibles, SendNonFungibles, GrantBudget, GrantAllowance,
+    // MintKeylessBadge, SendFungiblesMulti, Swap and ManageResource types) or None
     resource: Option<ResourceAddress>,
 
-    // Amount (SendFungibles, Stake, and Unstake types) or None
+    // Amount (SendFungibles, Stake, Unstake, GrantBudget, GrantAllowance, Swap and
+    // RedeemFromPool types) or None
     amount: Option<Decimal>,
 
-    // Non fungible ids (DisableBadge, EnableBadge, SendNonFungibles and ClaimUnstakedXrd type) or None
+    // Non fungible ids (DisableBadge, EnableBadge, SendNonFungibles, ClaimUnstakedXrd,
+    // RenewMember, DisableInactiveMembers, GrantBudget, RevokeAllowance and MintKeylessBadge
+    // type) or None
     non_fungible_ids: Option<Vec<NonFungibleLocalId>>,
 
-    // Recipent account address (MintBadge, SendFungibles, SendNonFungibles types) or None
+    // Recipent account address (MintBadge, SendFungibles, SendNonFungibles, InviteMember,
+    // RevokeInvitation, ApproveApplication and RejectApplication types) or None
     recipient: Option<Global<Account>>,
 
-    // Recipient component address (TransferAccountBadge type) or None
+    // Recipient component address (TransferAccountBadge and GrantAllowance types), target
+    // component (CallMethod type), pool (Swap, ContributeToPool and RedeemFromPool types) or
+    // None
     component: Option<Global<AnyComponent>>,
 
     // Validator address (Stake, Unstake and ClaimUnstakedXrd type) or None
     validator: Option<Global<Validator>>,
+
+    // Metadata key and value (SetMemberBadgeMetadata type) or None
+    metadata: Option<(String, MetadataValue)>,
+
+    // Date (RenewMember, InviteMember, GrantBudget and GrantAllowance types) or None
+    date: Option<Instant>,
+
+    // Number of seconds (DisableInactiveMembers, GrantBudget and GrantAllowance types) or None
+    period: Option<i64>,
+
+    // Ordered list of actions (Batch type) or None
+    actions: Option<Vec<Action>>,
+
+    // List of recipient accounts and amounts (SendFungiblesMulti type) or None
+    payments: Option<Vec<(Global<Account>, Decimal)>>,
+
+    // Method name, SBOR-encoded arguments and resources to withdraw and pass as buckets to the
+    // method (CallMethod type) or None
+    method_call: Option<MethodCall>,
+
+    // Output resource and minimum output amount (Swap type) or None
+    output: Option<(ResourceAddress, Decimal)>,
+
+    // List of resources and amounts to contribute to a pool (ContributeToPool type) or None
+    contributions: Option<Vec<(ResourceAddress, Decimal)>>,
+
+    // Badge held in the DAO treasury to create a proof of (ManageResource type) or None
+    badge: Option<(ResourceAddress, ResourceSpecifier)>,
+
+    // Action to perform on the resource (ManageResource type) or None
+    resource_action: Option<ResourceAction>,
+
+    // Resource to create (CreateResource type) or None
+    new_resource: Option<NewResource>,
 }
 
 /* This event is emitted when an operation is signed by the first cosigner.
@@ -83,9 +191,23 @@ pub struct NewOperationEvent {
     recipient: Option<Global<Account>>,
     component: Option<Global<AnyComponent>>,
     validator: Option<Global<Validator>>,
+    metadata: Option<(String, MetadataValue)>,
+    date: Option<Instant>,
+    period: Option<i64>,
+    actions: Option<Vec<Action>>,
+    payments: Option<Vec<(Global<Account>, Decimal)>>,
+    method_call: Option<MethodCall>,
+    output: Option<(ResourceAddress, Decimal)>,
+    contributions: Option<Vec<(ResourceAddress, Decimal)>>,
+    badge: Option<(ResourceAddress, ResourceSpecifier)>,
+    resource_action: Option<ResourceAction>,
+    new_resource: Option<NewResource>,
 
     // Member badge id of the first signer of the operation
     signer_badge_id: NonFungibleLocalId,
+
+    // Canonical hash of the operation
+    operation_hash: Hash,
 }
 
 /* This event is emitted when an operation has enough cosigners and is actually executed.
@@ -100,9 +222,46 @@ pub struct OperationExecutedEvent {
     recipient: Option<Global<Account>>,
     component: Option<Global<AnyComponent>>,
     validator: Option<Global<Validator>>,
+    metadata: Option<(String, MetadataValue)>,
+    date: Option<Instant>,
+    period: Option<i64>,
+    actions: Option<Vec<Action>>,
+    payments: Option<Vec<(Global<Account>, Decimal)>>,
+    method_call: Option<MethodCall>,
+    output: Option<(ResourceAddress, Decimal)>,
+    contributions: Option<Vec<(ResourceAddress, Decimal)>>,
+    badge: Option<(ResourceAddress, ResourceSpecifier)>,
+    resource_action: Option<ResourceAction>,
+    new_resource: Option<NewResource>,
+
+    // Resources and amounts deposited in the DAO treasury by the execution of a
+    // ContributeToPool, RedeemFromPool or CreateResource operation
+    received: Vec<(ResourceAddress, Decimal)>,
 
     // List of cosigners, including badge ids and transaction hashes
     cosigners: Cosigners,
+
+    // Canonical hash of the operation
+    operation_hash: Hash,
+}
+
+/* This event is emitted when the recipient of a SendFungibles, SendNonFungibles or
+ * SendFungiblesMulti operation refuses the deposit and the payment is stored in the AccountLocker
+ * for him to claim.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PaymentStoredEvent {
+    recipient: Global<Account>,
+    resource: ResourceAddress,
+    amount: Option<Decimal>,
+    non_fungible_ids: Option<Vec<NonFungibleLocalId>>,
+}
+
+/* This event is emitted when a CreateResource operation creates a new resource.
+ */
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ResourceCreatedEvent {
+    resource: ResourceAddress,
 }
 
 /* u8 to OperationType conversion.
@@ -114,17 +273,36 @@ pub struct OperationExecutedEvent {
 impl From<u8> for OperationType {
     fn from(orig: u8) -> Self {
         match orig {
-            0 => return OperationType::MintBadge,
-            1 => return OperationType::DisableBadge,
-            2 => return OperationType::EnableBadge,
-            3 => return OperationType::IncreaseMinCosigners,
-            4 => return OperationType::DecreaseMinCosigners,
-            5 => return OperationType::SendFungibles,
-            6 => return OperationType::SendNonFungibles,
-            7 => return OperationType::TransferAccountBadge,
-            8 => return OperationType::Stake,
-            9 => return OperationType::Unstake,
-            10 => return OperationType::ClaimUnstakedXrd,
+            0 => OperationType::MintBadge,
+            1 => OperationType::DisableBadge,
+            2 => OperationType::EnableBadge,
+            3 => OperationType::IncreaseMinCosigners,
+            4 => OperationType::DecreaseMinCosigners,
+            5 => OperationType::SendFungibles,
+            6 => OperationType::SendNonFungibles,
+            7 => OperationType::TransferAccountBadge,
+            8 => OperationType::Stake,
+            9 => OperationType::Unstake,
+            10 => OperationType::ClaimUnstakedXrd,
+            11 => OperationType::SetMemberBadgeMetadata,
+            12 => OperationType::RenewMember,
+            13 => OperationType::DisableInactiveMembers,
+            14 => OperationType::InviteMember,
+            15 => OperationType::RevokeInvitation,
+            16 => OperationType::ApproveApplication,
+            17 => OperationType::RejectApplication,
+            18 => OperationType::GrantBudget,
+            19 => OperationType::GrantAllowance,
+            20 => OperationType::RevokeAllowance,
+            21 => OperationType::MintKeylessBadge,
+            22 => OperationType::Batch,
+            23 => OperationType::SendFungiblesMulti,
+            24 => OperationType::CallMethod,
+            25 => OperationType::Swap,
+            26 => OperationType::ContributeToPool,
+            27 => OperationType::RedeemFromPool,
+            28 => OperationType::ManageResource,
+            29 => OperationType::CreateResource,
             _  => Runtime::panic("Unknown operation type".to_string()),
         }
     }
@@ -135,35 +313,302 @@ impl Operation {
     /* Create a new operation.
      * Input parameters:
      * - description: human readable representation of the operation
-     * - operation_type: type of operation
-     * - resource: resource address to send (SendFungibles and SendNonFungibles types) or None
-     * - amount: amount to send (SendFungibles type) or None
-     * - non_fungible_ids: list of non fungibles to send (SendNonFungibles type) or None
-     * - recipient: recipent account address (MintBadge, SendFungibles, SendNonFungibles types) or None
-     * - component: recipient component address (TransferAccountBadge type) or None
+     * - details: type of operation and its parameters
      * Outputs:
      * - the Operation object
      */
     pub fn new(
         description: String,
-        operation_type: OperationType,
-        resource: Option<ResourceAddress>,
-        amount: Option<Decimal>,
-        non_fungible_ids: Option<Vec<NonFungibleLocalId>>,
-        recipient: Option<Global<Account>>,
-        component: Option<Global<AnyComponent>>,
-        validator: Option<Global<Validator>>,
+        details: OperationDetails,
     ) -> Operation {
-        Operation {
+        let mut operation = Operation {
             description: description.trim().to_string(),
-            operation_type: operation_type,
-            resource: resource,
-            amount: amount,
-            non_fungible_ids: non_fungible_ids,
-            recipient: recipient,
-            component: component,
-            validator: validator,
+            operation_type: details.operation_type(),
+            resource: None,
+            amount: None,
+            non_fungible_ids: None,
+            recipient: None,
+            component: None,
+            validator: None,
+            metadata: None,
+            date: None,
+            period: None,
+            actions: None,
+            payments: None,
+            method_call: None,
+            output: None,
+            contributions: None,
+            badge: None,
+            resource_action: None,
+            new_resource: None,
+        };
+
+        // Store each parameter in its field, so that the hash and the events of an operation
+        // don't depend on how it was created
+        match details {
+            OperationDetails::MintBadge(recipient)
+            | OperationDetails::RevokeInvitation(recipient)
+            | OperationDetails::ApproveApplication(recipient)
+            | OperationDetails::RejectApplication(recipient) => {
+                operation.recipient = Some(recipient);
+            },
+            OperationDetails::DisableBadge(non_fungible_id)
+            | OperationDetails::EnableBadge(non_fungible_id)
+            | OperationDetails::RevokeAllowance(non_fungible_id) => {
+                operation.non_fungible_ids = Some(vec![non_fungible_id]);
+            },
+            OperationDetails::IncreaseMinCosigners | OperationDetails::DecreaseMinCosigners => {},
+            OperationDetails::SendFungibles(resource, amount, recipient) => {
+                operation.resource = Some(resource);
+                operation.amount = Some(amount);
+                operation.recipient = Some(recipient);
+            },
+            OperationDetails::SendNonFungibles(resource, non_fungible_ids, recipient) => {
+                operation.resource = Some(resource);
+                operation.non_fungible_ids = Some(non_fungible_ids);
+                operation.recipient = Some(recipient);
+            },
+            OperationDetails::TransferAccountBadge(component) => {
+                operation.component = Some(component);
+            },
+            OperationDetails::Stake(amount, validator)
+            | OperationDetails::Unstake(amount, validator) => {
+                operation.amount = Some(amount);
+                operation.validator = Some(validator);
+            },
+            OperationDetails::ClaimUnstakedXrd(non_fungible_ids, validator) => {
+                operation.non_fungible_ids = Some(non_fungible_ids);
+                operation.validator = Some(validator);
+            },
+            OperationDetails::SetMemberBadgeMetadata(key, value) => {
+                operation.metadata = Some((key, value));
+            },
+            OperationDetails::RenewMember(non_fungible_id, valid_until) => {
+                operation.non_fungible_ids = Some(vec![non_fungible_id]);
+                operation.date = Some(valid_until);
+            },
+            OperationDetails::DisableInactiveMembers(period, non_fungible_ids) => {
+                operation.period = Some(period);
+                operation.non_fungible_ids = Some(non_fungible_ids);
+            },
+            OperationDetails::InviteMember(recipient, expiration) => {
+                operation.recipient = Some(recipient);
+                operation.date = Some(expiration);
+            },
+            OperationDetails::GrantBudget(non_fungible_id, resource, amount, period, expiration) => {
+                operation.non_fungible_ids = Some(vec![non_fungible_id]);
+                operation.resource = Some(resource);
+                operation.amount = Some(amount);
+                operation.period = Some(period);
+                operation.date = Some(expiration);
+            },
+            OperationDetails::GrantAllowance(holder, resource, amount, period, expiration) => {
+                operation.component = Some(holder);
+                operation.resource = Some(resource);
+                operation.amount = Some(amount);
+                operation.period = Some(period);
+                operation.date = Some(expiration);
+            },
+            OperationDetails::MintKeylessBadge(signer) => {
+                operation.resource = Some(signer.resource_address());
+                operation.non_fungible_ids = Some(vec![signer.local_id().clone()]);
+            },
+            OperationDetails::Batch(actions) => {
+                operation.actions = Some(actions);
+            },
+            OperationDetails::SendFungiblesMulti(resource, payments) => {
+                operation.resource = Some(resource);
+                operation.payments = Some(payments);
+            },
+            OperationDetails::CallMethod(component, method, arguments, withdrawals) => {
+                operation.component = Some(component);
+                operation.method_call = Some((method, arguments, withdrawals));
+            },
+            OperationDetails::Swap(pool, input_resource, input_amount, output_resource, min_output) => {
+                operation.component = Some(pool);
+                operation.resource = Some(input_resource);
+                operation.amount = Some(input_amount);
+                operation.output = Some((output_resource, min_output));
+            },
+            OperationDetails::ContributeToPool(pool, contributions) => {
+                operation.component = Some(pool);
+                operation.contributions = Some(contributions);
+            },
+            OperationDetails::RedeemFromPool(pool, amount) => {
+                operation.component = Some(pool);
+                operation.amount = Some(amount);
+            },
+            OperationDetails::ManageResource(resource, badge, resource_action) => {
+                operation.resource = Some(resource);
+                operation.badge = badge;
+                operation.resource_action = Some(resource_action);
+            },
+            OperationDetails::CreateResource(new_resource) => {
+                operation.new_resource = Some(new_resource);
+            },
         }
+
+        operation
+    }
+
+    /* Get the type of this operation.
+     * Outputs:
+     * - an element of the OperationType enum
+     */
+    pub fn operation_type(&self) -> OperationType {
+        self.operation_type
+    }
+
+    /* Get the human readable description of this operation.
+     * Outputs:
+     * - the description
+     */
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    /* Get the resource address of this operation.
+     * Outputs:
+     * - the resource address or None
+     */
+    pub fn resource(&self) -> Option<ResourceAddress> {
+        self.resource
+    }
+
+    /* Get the amount of this operation.
+     * Outputs:
+     * - the amount or None
+     */
+    pub fn amount(&self) -> Option<Decimal> {
+        self.amount
+    }
+
+    /* Get the non fungible ids of this operation.
+     * Outputs:
+     * - the list of non fungible ids or None
+     */
+    pub fn non_fungible_ids(&self) -> Option<Vec<NonFungibleLocalId>> {
+        self.non_fungible_ids.clone()
+    }
+
+    /* Get the recipient account of this operation.
+     * Outputs:
+     * - the recipient account or None
+     */
+    pub fn recipient(&self) -> Option<Global<Account>> {
+        self.recipient
+    }
+
+    /* Get the recipient component of this operation.
+     * Outputs:
+     * - the recipient component or None
+     */
+    pub fn component(&self) -> Option<Global<AnyComponent>> {
+        self.component
+    }
+
+    /* Get the validator of this operation.
+     * Outputs:
+     * - the validator or None
+     */
+    pub fn validator(&self) -> Option<Global<Validator>> {
+        self.validator
+    }
+
+    /* Get the metadata key and value of this operation.
+     * Outputs:
+     * - the metadata key and value or None
+     */
+    pub fn metadata(&self) -> Option<(String, MetadataValue)> {
+        self.metadata.clone()
+    }
+
+    /* Get the date of this operation.
+     * Outputs:
+     * - the date or None
+     */
+    pub fn date(&self) -> Option<Instant> {
+        self.date
+    }
+
+    /* Get the number of seconds of this operation.
+     * Outputs:
+     * - the number of seconds or None
+     */
+    pub fn period(&self) -> Option<i64> {
+        self.period
+    }
+
+    /* Get the actions of this operation.
+     * Outputs:
+     * - the ordered list of actions or None
+     */
+    pub fn actions(&self) -> Option<Vec<Action>> {
+        self.actions.clone()
+    }
+
+    /* Get the payments of this operation.
+     * Outputs:
+     * - the list of recipient accounts and amounts or None
+     */
+    pub fn payments(&self) -> Option<Vec<(Global<Account>, Decimal)>> {
+        self.payments.clone()
+    }
+
+    /* Get the method call of this operation.
+     * Outputs:
+     * - the method name, SBOR-encoded arguments and resources to withdraw or None
+     */
+    pub fn method_call(&self) -> Option<MethodCall> {
+        self.method_call.clone()
+    }
+
+    /* Get the output resource and minimum output amount of this operation.
+     * Outputs:
+     * - the output resource and minimum output amount or None
+     */
+    pub fn output(&self) -> Option<(ResourceAddress, Decimal)> {
+        self.output
+    }
+
+    /* Get the contributions of this operation.
+     * Outputs:
+     * - the list of resources and amounts to contribute to a pool or None
+     */
+    pub fn contributions(&self) -> Option<Vec<(ResourceAddress, Decimal)>> {
+        self.contributions.clone()
+    }
+
+    /* Get the badge of this operation.
+     * Outputs:
+     * - the badge held in the DAO treasury to create a proof of or None
+     */
+    pub fn badge(&self) -> Option<(ResourceAddress, ResourceSpecifier)> {
+        self.badge.clone()
+    }
+
+    /* Get the resource action of this operation.
+     * Outputs:
+     * - the action to perform on the resource or None
+     */
+    pub fn resource_action(&self) -> Option<ResourceAction> {
+        self.resource_action.clone()
+    }
+
+    /* Get the resource to create of this operation.
+     * Outputs:
+     * - the description of the resource to create or None
+     */
+    pub fn new_resource(&self) -> Option<NewResource> {
+        self.new_resource.clone()
+    }
+
+    /* Compute the canonical hash of this operation: the blake2b hash of its SBOR encoding.
+     * Outputs:
+     * - the hash of the operation
+     */
+    pub fn hash(&self) -> Hash {
+        CryptoUtils::blake2b_256_hash(scrypto_encode(self).unwrap())
     }
 
     /* Emit the NewOperationEvent.
@@ -184,18 +629,84 @@ impl Operation {
                 recipient: self.recipient,
                 component: self.component,
                 validator: self.validator,
-                signer_badge_id: signer_badge_id,
+                metadata: self.metadata.clone(),
+                date: self.date,
+                period: self.period,
+                actions: self.actions.clone(),
+                payments: self.payments.clone(),
+                method_call: self.method_call.clone(),
+                output: self.output,
+                contributions: self.contributions.clone(),
+                badge: self.badge.clone(),
+                resource_action: self.resource_action.clone(),
+                new_resource: self.new_resource.clone(),
+                signer_badge_id,
+                operation_hash: self.hash(),
             }
         );
     }
 
+    /* Check whether signing this operation is a conflict of interest for a member: he is the
+     * recipient of the operation or the beneficiary of an operation on member badges (agreeing
+     * to disable his own badge is not a conflict of interest), or of one of the actions of a
+     * Batch operation, or one of the recipients of a SendFungiblesMulti operation, or the
+     * recipient of the resources minted by a ManageResource operation.
+     * Input parameters:
+     * - badge_id: the non fungible id of the member badge of the signer
+     * - accounts: the accounts of the signer
+     * Outputs:
+     * - true if the member benefits from or is targeted by this operation
+     */
+    pub fn is_conflict_of_interest(
+        &self,
+        badge_id: &NonFungibleLocalId,
+        accounts: &[Global<Account>],
+    ) -> bool {
+        if let Some(recipient) = self.recipient {
+            if accounts.contains(&recipient) {
+                return true;
+            }
+        }
+
+        if let Some(component) = self.component {
+            if accounts.iter().any(|account| account.address() == component.address()) {
+                return true;
+            }
+        }
+
+        match self.operation_type {
+            OperationType::EnableBadge |
+            OperationType::RenewMember |
+            OperationType::GrantBudget =>
+                self.non_fungible_ids.as_ref().unwrap().contains(badge_id),
+            // A member agreeing to step down doesn't benefit from it
+            OperationType::DisableBadge => false,
+            OperationType::Batch =>
+                self.actions.as_ref().unwrap().iter().any(
+                    |action| action.to_operation(&self.description)
+                        .is_conflict_of_interest(badge_id, accounts)
+                ),
+            OperationType::SendFungiblesMulti =>
+                self.payments.as_ref().unwrap().iter().any(
+                    |(recipient, _)| accounts.contains(recipient)
+                ),
+            OperationType::ManageResource =>
+                self.resource_action.as_ref().unwrap().recipient().is_some_and(
+                    |recipient| accounts.contains(&recipient)
+                ),
+            _ => false,
+        }
+    }
+
     /* Emit the OperationExecutedEvent.
      * Input parameters:
      * - cosigners: list of signers of the executed operation
+     * - received: resources and amounts deposited in the DAO treasury by the execution
      */
     pub fn emit_executed_event(
         &self,
         cosigners: Cosigners,
+        received: Vec<(ResourceAddress, Decimal)>,
     ) {
         Runtime::emit_event(
             OperationExecutedEvent {
@@ -207,7 +718,54 @@ impl Operation {
                 recipient: self.recipient,
                 component: self.component,
                 validator: self.validator,
-                cosigners: cosigners,
+                metadata: self.metadata.clone(),
+                date: self.date,
+                period: self.period,
+                actions: self.actions.clone(),
+                payments: self.payments.clone(),
+                method_call: self.method_call.clone(),
+                output: self.output,
+                contributions: self.contributions.clone(),
+                badge: self.badge.clone(),
+                resource_action: self.resource_action.clone(),
+                new_resource: self.new_resource.clone(),
+                received,
+                cosigners,
+                operation_hash: self.hash(),
+            }
+        );
+    }
+
+    /* Emit the ResourceCreatedEvent.
+     * Input parameters:
+     * - resource: address of the resource created by a CreateResource operation
+     */
+    pub fn emit_resource_created_event(
+        resource: ResourceAddress,
+    ) {
+        Runtime::emit_event(
+            ResourceCreatedEvent {
+                resource,
+            }
+        );
+    }
+
+    /* Emit the PaymentStoredEvent.
+     * Input parameters:
+     * - recipient: the account that refused the deposit
+     * - amount: the stored amount of fungibles or None for non fungibles
+     */
+    pub fn emit_payment_stored_event(
+        &self,
+        recipient: Global<Account>,
+        amount: Option<Decimal>,
+    ) {
+        Runtime::emit_event(
+            PaymentStoredEvent {
+                recipient,
+                resource: self.resource.unwrap(),
+                amount,
+                non_fungible_ids: self.non_fungible_ids.clone(),
             }
         );
     }
@@ -0,0 +1,147 @@
+use scrypto::prelude::*;
+use crate::operation::*;
+use crate::action::*;
+use crate::resource_action::*;
+use crate::new_resource::*;
+
+/* OperationDetails are the parameters of an operation: each variant corresponds to an operation
+ * type and holds exactly the parameters that type requires.
+ */
+#[derive(ScryptoSbor, PartialEq, Clone)]
+pub enum OperationDetails {
+
+    // account to send the new member badge to
+    MintBadge(Global<Account>),
+
+    // id of the member badge to disable
+    DisableBadge(NonFungibleLocalId),
+
+    // id of the member badge to enable
+    EnableBadge(NonFungibleLocalId),
+
+    // no parameters
+    IncreaseMinCosigners,
+
+    // no parameters
+    DecreaseMinCosigners,
+
+    // resource address, amount and recipient of the fungibles to send
+    SendFungibles(ResourceAddress, Decimal, Global<Account>),
+
+    // resource address, ids and recipient of the non fungibles to send
+    SendNonFungibles(ResourceAddress, Vec<NonFungibleLocalId>, Global<Account>),
+
+    // component to send the account badge to
+    TransferAccountBadge(Global<AnyComponent>),
+
+    // amount of XRD to stake and validator to stake to
+    Stake(Decimal, Global<Validator>),
+
+    // amount of LSU to unstake and validator to unstake from
+    Unstake(Decimal, Global<Validator>),
+
+    // ids of the claim NFTs and validator to claim the XRD from
+    ClaimUnstakedXrd(Vec<NonFungibleLocalId>, Global<Validator>),
+
+    // metadata key and value to set on the member badges
+    SetMemberBadgeMetadata(String, MetadataValue),
+
+    // id of the member badge to renew and new end of the member term
+    RenewMember(NonFungibleLocalId, Instant),
+
+    // inactivity period in seconds and ids of the inactive member badges to disable
+    DisableInactiveMembers(i64, Vec<NonFungibleLocalId>),
+
+    // invited account and expiration of the invitation
+    InviteMember(Global<Account>, Instant),
+
+    // account whose invitation is revoked
+    RevokeInvitation(Global<Account>),
+
+    // account whose application is approved
+    ApproveApplication(Global<Account>),
+
+    // account whose application is rejected
+    RejectApplication(Global<Account>),
+
+    // member badge id, resource address, amount, period in seconds and expiration of a budget
+    GrantBudget(NonFungibleLocalId, ResourceAddress, Decimal, i64, Instant),
+
+    // recipient component, resource address, amount, period in seconds and expiration of an
+    // allowance
+    GrantAllowance(Global<AnyComponent>, ResourceAddress, Decimal, i64, Instant),
+
+    // id of the allowance badge to revoke
+    RevokeAllowance(NonFungibleLocalId),
+
+    // global id of the virtual signature badge of the keyless member
+    MintKeylessBadge(NonFungibleGlobalId),
+
+    // ordered list of actions
+    Batch(Vec<Action>),
+
+    // resource address and list of recipient accounts and amounts
+    SendFungiblesMulti(ResourceAddress, Vec<(Global<Account>, Decimal)>),
+
+    // component to call, method name, SBOR-encoded arguments and resources to withdraw and pass
+    // as buckets to the method
+    CallMethod(Global<AnyComponent>, String, Vec<u8>, Vec<(ResourceAddress, ResourceSpecifier)>),
+
+    // pool, input resource and amount, output resource and minimum output amount
+    Swap(Global<AnyComponent>, ResourceAddress, Decimal, ResourceAddress, Decimal),
+
+    // pool and resources and amounts to contribute to it
+    ContributeToPool(Global<AnyComponent>, Vec<(ResourceAddress, Decimal)>),
+
+    // pool and amount of pool units to redeem
+    RedeemFromPool(Global<AnyComponent>, Decimal),
+
+    // resource to manage, optional badge held in the DAO treasury to create a proof of and
+    // action to perform
+    ManageResource(ResourceAddress, Option<(ResourceAddress, ResourceSpecifier)>, ResourceAction),
+
+    // resource to create
+    CreateResource(NewResource),
+}
+
+impl OperationDetails {
+
+    /* Get the type of the operation these details belong to.
+     * Outputs:
+     * - an element of the OperationType enum
+     */
+    pub fn operation_type(&self) -> OperationType {
+        match self {
+            OperationDetails::MintBadge(..) => OperationType::MintBadge,
+            OperationDetails::DisableBadge(..) => OperationType::DisableBadge,
+            OperationDetails::EnableBadge(..) => OperationType::EnableBadge,
+            OperationDetails::IncreaseMinCosigners => OperationType::IncreaseMinCosigners,
+            OperationDetails::DecreaseMinCosigners => OperationType::DecreaseMinCosigners,
+            OperationDetails::SendFungibles(..) => OperationType::SendFungibles,
+            OperationDetails::SendNonFungibles(..) => OperationType::SendNonFungibles,
+            OperationDetails::TransferAccountBadge(..) => OperationType::TransferAccountBadge,
+            OperationDetails::Stake(..) => OperationType::Stake,
+            OperationDetails::Unstake(..) => OperationType::Unstake,
+            OperationDetails::ClaimUnstakedXrd(..) => OperationType::ClaimUnstakedXrd,
+            OperationDetails::SetMemberBadgeMetadata(..) => OperationType::SetMemberBadgeMetadata,
+            OperationDetails::RenewMember(..) => OperationType::RenewMember,
+            OperationDetails::DisableInactiveMembers(..) => OperationType::DisableInactiveMembers,
+            OperationDetails::InviteMember(..) => OperationType::InviteMember,
+            OperationDetails::RevokeInvitation(..) => OperationType::RevokeInvitation,
+            OperationDetails::ApproveApplication(..) => OperationType::ApproveApplication,
+            OperationDetails::RejectApplication(..) => OperationType::RejectApplication,
+            OperationDetails::GrantBudget(..) => OperationType::GrantBudget,
+            OperationDetails::GrantAllowance(..) => OperationType::GrantAllowance,
+            OperationDetails::RevokeAllowance(..) => OperationType::RevokeAllowance,
+            OperationDetails::MintKeylessBadge(..) => OperationType::MintKeylessBadge,
+            OperationDetails::Batch(..) => OperationType::Batch,
+            OperationDetails::SendFungiblesMulti(..) => OperationType::SendFungiblesMulti,
+            OperationDetails::CallMethod(..) => OperationType::CallMethod,
+            OperationDetails::Swap(..) => OperationType::Swap,
+            OperationDetails::ContributeToPool(..) => OperationType::ContributeToPool,
+            OperationDetails::RedeemFromPool(..) => OperationType::RedeemFromPool,
+            OperationDetails::ManageResource(..) => OperationType::ManageResource,
+            OperationDetails::CreateResource(..) => OperationType::CreateResource,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+/* A ResourceAction is what a ManageResource operation does with a resource whose roles are
+ * satisfied by a badge held in the DAO treasury.
+ */
+#[derive(ScryptoSbor, PartialEq, Clone)]
+pub enum ResourceAction {
+
+    // amount of fungibles to mint and recipient
+    MintFungibles(Decimal, Global<Account>),
+
+    // id, SBOR-encoded data and recipient of the non fungible to mint
+    MintNonFungible(NonFungibleLocalId, Vec<u8>, Global<Account>),
+
+    // amount of fungibles to burn from the DAO treasury
+    BurnFungibles(Decimal),
+
+    // ids of the non fungibles to burn from the DAO treasury
+    BurnNonFungibles(Vec<NonFungibleLocalId>),
+
+    // id of the non fungible, name of the field to update and its SBOR-encoded new value
+    UpdateNonFungibleData(NonFungibleLocalId, String, Vec<u8>),
+
+    // metadata key and value to set on the resource
+    SetMetadata(String, MetadataValue),
+}
+
+impl ResourceAction {
+
+    /* Get the recipient of the resources minted by this action.
+     * Outputs:
+     * - the recipient account or None if the action doesn't mint anything
+     */
+    pub fn recipient(&self) -> Option<Global<Account>> {
+        match self {
+            ResourceAction::MintFungibles(_, recipient) |
+            ResourceAction::MintNonFungible(_, _, recipient) => Some(*recipient),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+#[test]
+fn test_refused_fungibles_stored_in_locker() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+    let before = env.xrd_balance(dave_addr);
+
+    // The payment can't be delivered but the operation is executed anyway
+    env.send_fungibles(&env.alice.clone(), "pay dave", XRD, dec!("100"), dave_addr)
+        .expect_commit_success();
+    let r = env.send_fungibles(&env.bob.clone(), "pay dave", XRD, dec!("100"), dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "PaymentStoredEvent"));
+    assert_eq!(env.xrd_balance(dave_addr), before);
+
+    // Dave claims the payment from the locker
+    let locker = env.get_account_locker();
+    env.ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(locker, "claim", manifest_args!(dave_addr, XRD, dec!("100")))
+            .deposit_entire_worktop(dave_addr)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(dave_pk)],
+    ).expect_commit_success();
+    assert_eq!(env.xrd_balance(dave_addr) - before, dec!("100"));
+}
+
+#[test]
+fn test_refused_non_fungibles_stored_in_locker() {
+    let mut env = Helper::new_2_of_3();
+    let nft_resource = env.ledger.create_non_fungible_resource(env.dao_account);
+    let nft_id = NonFungibleLocalId::integer(1);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+
+    env.send_non_fungibles(
+        &env.alice.clone(), "send nft to dave", nft_resource, vec![nft_id.clone()], dave_addr,
+    ).expect_commit_success();
+    let r = env.send_non_fungibles(
+        &env.bob.clone(), "send nft to dave", nft_resource, vec![nft_id.clone()], dave_addr,
+    );
+    r.expect_commit_success();
+    assert!(has_event(&r, "PaymentStoredEvent"));
+    assert_eq!(env.nft_count(dave_addr, nft_resource), 0);
+
+    // Dave claims the non fungible from the locker
+    let locker = env.get_account_locker();
+    env.ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                locker, "claim_non_fungibles", manifest_args!(dave_addr, nft_resource, vec![nft_id.clone()]),
+            )
+            .deposit_entire_worktop(dave_addr)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(dave_pk)],
+    ).expect_commit_success();
+    assert_eq!(env.nft_ids(dave_addr, nft_resource), vec![nft_id]);
+}
+
+#[test]
+fn test_refused_payment_aborted() {
+    let mut env = Helper::new_with_refused_deposits_aborted(2, 3);
+    env.fund_dao();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+
+    // With abort_refused_deposits the payment is not stored, the operation fails
+    env.send_fungibles(&env.alice.clone(), "pay dave", XRD, dec!("100"), dave_addr)
+        .expect_commit_success();
+    env.send_fungibles(&env.bob.clone(), "pay dave", XRD, dec!("100"), dave_addr)
+        .expect_commit_failure();
+}
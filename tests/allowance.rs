@@ -0,0 +1,79 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const DAY: i64 = 86400;
+
+fn grant_dave_allowance(env: &mut Helper, amount: Decimal, expiration: Instant) {
+    let dave_addr = env.dave.0;
+    env.grant_allowance(&env.alice.clone(), "payroll", dave_addr, XRD, amount, DAY, expiration)
+        .expect_commit_success();
+    let r = env.grant_allowance(&env.bob.clone(), "payroll", dave_addr, XRD, amount, DAY, expiration);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_withdraw_with_allowance() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk) = env.dave;
+    let expiration = env.now().add_days(30).unwrap();
+    grant_dave_allowance(&mut env, dec!("100"), expiration);
+    assert_eq!(env.nft_count(dave_addr, env.allowance_badge), 1);
+    let dave_balance = env.xrd_balance(dave_addr);
+
+    // Dave withdraws without the signatures of the members
+    let r = env.withdraw_with_allowance(
+        dave_addr, &dave_pk, NonFungibleLocalId::integer(1), dec!("70"),
+    );
+    r.expect_commit_success();
+    assert!(has_event(&r, "AllowanceWithdrawnEvent"));
+    assert_eq!(env.xrd_balance(dave_addr), dave_balance + dec!("70"));
+
+    // Only 30 XRD are left for today
+    env.withdraw_with_allowance(dave_addr, &dave_pk, NonFungibleLocalId::integer(1), dec!("31"))
+        .expect_commit_failure();
+
+    // A new period starts tomorrow
+    env.advance_time(DAY);
+    env.withdraw_with_allowance(dave_addr, &dave_pk, NonFungibleLocalId::integer(1), dec!("100"))
+        .expect_commit_success();
+}
+
+#[test]
+fn test_allowance_expiration() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk) = env.dave;
+    let expiration = env.now().add_days(30).unwrap();
+    grant_dave_allowance(&mut env, dec!("100"), expiration);
+
+    env.advance_time(30 * DAY);
+    env.withdraw_with_allowance(dave_addr, &dave_pk, NonFungibleLocalId::integer(1), dec!("10"))
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_revoke_allowance() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk) = env.dave;
+    let expiration = env.now().add_days(30).unwrap();
+    grant_dave_allowance(&mut env, dec!("100"), expiration);
+
+    env.revoke_allowance(&env.alice.clone(), "stop payroll", NonFungibleLocalId::integer(1))
+        .expect_commit_success();
+    env.revoke_allowance(&env.carol.clone(), "stop payroll", NonFungibleLocalId::integer(1))
+        .expect_commit_success();
+
+    // The badge is still in Dave's account but useless
+    assert_eq!(env.nft_count(dave_addr, env.allowance_badge), 1);
+    env.withdraw_with_allowance(dave_addr, &dave_pk, NonFungibleLocalId::integer(1), dec!("10"))
+        .expect_commit_failure();
+
+    // An unknown allowance can't be revoked
+    env.revoke_allowance(&env.alice.clone(), "stop payroll", NonFungibleLocalId::integer(1))
+        .expect_commit_failure();
+}
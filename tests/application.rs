@@ -0,0 +1,150 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+use helper::APPLICATION_PERIOD;
+
+#[test]
+fn test_approve_application() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), true);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let balance = env.ledger.get_component_balance(dave_addr, XRD);
+
+    let r = env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("100")));
+    r.expect_commit_success();
+    assert!(has_event(&r, "NewApplicationEvent"));
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), balance - dec!("100"));
+
+    env.approve_application(&env.alice.clone(), "approve dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0, "Only 1 signature");
+    env.approve_application(&env.bob.clone(), "approve dave", dave_addr).expect_commit_success();
+
+    // Dave got the member badge and the bond back
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), balance);
+}
+
+#[test]
+fn test_apply_for_membership_checks() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), true);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let alice_pk = env.alice.1;
+
+    // Insufficient bond
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("10")))
+        .expect_commit_failure();
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", None).expect_commit_failure();
+
+    // Only the owner of an account can apply for membership with it
+    env.apply_for_membership(dave_addr, &alice_pk, "let dave in", None).expect_commit_failure();
+
+    // One application at a time
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_success();
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_get_application() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), true);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+
+    assert!(env.get_application(dave_addr).is_none());
+
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("60")))
+        .expect_commit_success();
+    let (message, bond, expiration) = env.get_application(dave_addr).unwrap();
+    assert_eq!(message, "let me in");
+    assert_eq!(bond, dec!("60"));
+    assert!(expiration > env.now());
+}
+
+#[test]
+fn test_members_cannot_apply() {
+    let mut env = Helper::new_2_of_3();
+    let (alice_addr, alice_pk) = (env.alice.0, env.alice.1);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+
+    // Alice's account holds her member badge
+    env.apply_for_membership(alice_addr, &alice_pk, "let me in again", None)
+        .expect_commit_failure();
+
+    // Dave's account is registered for Alice's member badge
+    env.register_account(&env.alice.clone(), dave_addr, &dave_pk).expect_commit_success();
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", None).expect_commit_failure();
+}
+
+#[test]
+fn test_rejected_application_bond_forfeited() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), true);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_success();
+    env.reject_application(&env.alice.clone(), "reject dave", dave_addr).expect_commit_success();
+    env.reject_application(&env.bob.clone(), "reject dave", dave_addr).expect_commit_success();
+
+    assert_eq!(env.ledger.get_component_balance(env.dao_account, XRD), dec!("50"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+    env.approve_application(&env.alice.clone(), "approve dave", dave_addr).expect_commit_failure();
+}
+
+#[test]
+fn test_expired_application_bond_refunded() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), false);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let balance = env.ledger.get_component_balance(dave_addr, XRD);
+
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_success();
+    env.approve_application(&env.alice.clone(), "approve dave", dave_addr).expect_commit_success();
+
+    // Too early to expire the application
+    env.expire_application(dave_addr).expect_commit_failure();
+
+    env.advance_time(APPLICATION_PERIOD + 1);
+    env.approve_application(&env.bob.clone(), "approve dave", dave_addr).expect_commit_failure();
+    env.expire_application(dave_addr).expect_commit_success();
+
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), balance);
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+}
+
+#[test]
+fn test_refused_bond_refund_stored_in_locker() {
+    let mut env = Helper::new_with_application_bond(2, 3, dec!("50"), false);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let balance = env.ledger.get_component_balance(dave_addr, XRD);
+
+    // Dave refuses deposits, this can't block the rejection of his application
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_success();
+    env.reject_deposits(dave_addr, dave_pk);
+    env.reject_application(&env.alice.clone(), "reject dave", dave_addr).expect_commit_success();
+    let r = env.reject_application(&env.bob.clone(), "reject dave", dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "BondStoredEvent"));
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), balance - dec!("50"));
+
+    // Nor the expiration of a new one
+    env.apply_for_membership(dave_addr, &dave_pk, "let me in", Some(dec!("50")))
+        .expect_commit_success();
+    env.advance_time(APPLICATION_PERIOD + 1);
+    let r = env.expire_application(dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "BondStoredEvent"));
+
+    // Dave claims both bonds from the locker
+    let locker = env.get_account_locker();
+    env.ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(locker, "claim", manifest_args!(dave_addr, XRD, dec!("100")))
+            .deposit_entire_worktop(dave_addr)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(dave_pk)],
+    ).expect_commit_success();
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), balance);
+}
@@ -0,0 +1,54 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::OperationDetails;
+use helper::has_event;
+
+#[test]
+fn test_sign_operation_with_auth_zone() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let (alice, bob) = (env.alice.clone(), env.bob.clone());
+
+    // Alice creates the operation with her badge in the auth zone
+    env.sign_operation_with_auth_zone(
+        &[&alice], "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+
+    // Bob signs it the same way
+    let r = env.sign_operation_with_auth_zone(
+        &[&bob], "add dave", OperationDetails::MintBadge(dave_addr),
+    );
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_auth_zone_badge_must_be_present() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+
+    // Alice puts her own badge in the auth zone but claims to be Bob
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            env.alice.0, env.member_badge, indexset![env.alice.2.clone()],
+        )
+        .call_method(
+            env.component,
+            "sign_operation_with_auth_zone",
+            manifest_args!(
+                vec![env.bob.2.clone()],
+                "add dave".to_owned(),
+                OperationDetails::MintBadge(dave_addr),
+            ),
+        )
+        .build();
+    env.ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(env.alice.1)],
+    ).expect_commit_failure();
+}
@@ -0,0 +1,55 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::Action;
+
+#[test]
+fn test_batch_executes_all_actions() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let carol_id = env.carol.2.clone();
+    let before = env.xrd_balance(dave_addr);
+    let actions = vec![
+        Action::SendFungibles(XRD, dec!("10"), dave_addr),
+        Action::SendFungibles(XRD, dec!("20"), dave_addr),
+        Action::DisableBadge(carol_id),
+    ];
+
+    // Alice and Bob approve the whole batch once
+    env.batch(&env.alice.clone(), "payroll", actions.clone()).expect_commit_success();
+    assert_eq!(env.xrd_balance(dave_addr), before, "Not yet executed");
+    let r = env.batch(&env.bob.clone(), "payroll", actions);
+    let executed_events = r.expect_commit_success()
+        .application_events
+        .iter()
+        .filter(|(id, _)| id.1.as_str() == "OperationExecutedEvent")
+        .count();
+    assert_eq!(executed_events, 1);
+    assert_eq!(env.xrd_balance(dave_addr) - before, dec!("30"));
+
+    // Carol's badge has been disabled
+    env.batch(
+        &env.carol.clone(), "pay dave", vec![Action::SendFungibles(XRD, dec!("1"), dave_addr)],
+    ).expect_commit_failure();
+}
+
+#[test]
+fn test_batch_is_atomic() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let before = env.xrd_balance(dave_addr);
+
+    // Each send is possible, both together are not
+    let actions = vec![
+        Action::SendFungibles(XRD, dec!("6000"), dave_addr),
+        Action::SendFungibles(XRD, dec!("6000"), dave_addr),
+    ];
+    env.batch(&env.alice.clone(), "overspend", actions.clone()).expect_commit_success();
+    env.batch(&env.bob.clone(), "overspend", actions).expect_commit_failure();
+    assert_eq!(env.xrd_balance(dave_addr), before);
+
+    // An empty batch makes no sense
+    env.batch(&env.alice.clone(), "nothing", vec![]).expect_commit_failure();
+}
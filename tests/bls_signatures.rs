@@ -0,0 +1,113 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::OperationDetails;
+use helper::has_event;
+
+/// Register a BLS key derived from `seed` for a member.
+fn register_key(
+    env: &mut Helper,
+    member: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+    seed: u64,
+) -> Bls12381G1PrivateKey {
+    let sk = Bls12381G1PrivateKey::from_u64(seed).unwrap();
+    let pk = sk.public_key();
+    env.register_bls_key(member, pk, sk.sign_v1(&pk.0)).expect_commit_success();
+    sk
+}
+
+fn add_dave_hash(env: &mut Helper) -> Hash {
+    let dave_addr = env.dave.0;
+    env.get_operation_hash(
+        "add dave", OperationDetails::MintBadge(dave_addr),
+    )
+}
+
+fn aggregate(keys: &[&Bls12381G1PrivateKey], message: Hash) -> Bls12381G2Signature {
+    let signatures: Vec<Bls12381G2Signature> = keys
+        .iter()
+        .map(|key| key.sign_v1(&message.0))
+        .collect();
+    Bls12381G2Signature::aggregate(&signatures, true).unwrap()
+}
+
+#[test]
+fn test_execute_with_aggregate_signature() {
+    let mut env = Helper::new_3_of_3();
+    let dave_addr = env.dave.0;
+    let (bob, carol) = (env.bob.clone(), env.carol.clone());
+    let bob_key = register_key(&mut env, &bob, 2);
+    let carol_key = register_key(&mut env, &carol, 3);
+
+    // Alice creates the operation on ledger, Bob and Carol approve it off-ledger
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let operation_hash = add_dave_hash(&mut env);
+    let message = env.get_bls_message(operation_hash);
+    let signature = aggregate(&[&bob_key, &carol_key], message);
+
+    let signers = vec![bob.2.clone(), carol.2.clone()];
+    let r = env.execute_with_signatures(operation_hash, signers.clone(), signature);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+
+    // The same signature can't be replayed once the operation has been executed
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.execute_with_signatures(operation_hash, signers, signature).expect_commit_failure();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_signature_cannot_be_replayed_after_removal() {
+    let mut env = Helper::new_3_of_3();
+    let dave_addr = env.dave.0;
+    let bob = env.bob.clone();
+    let bob_key = register_key(&mut env, &bob, 2);
+
+    // Alice creates the operation, Bob approves it off-ledger
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let operation_hash = add_dave_hash(&mut env);
+    let signature = aggregate(&[&bob_key], env.get_bls_message(operation_hash));
+    env.execute_with_signatures(operation_hash, vec![bob.2.clone()], signature)
+        .expect_commit_success();
+
+    // Bob changes his mind, nobody can restore his signature
+    env.remove_signature_with_details(&bob, "add dave", OperationDetails::MintBadge(dave_addr))
+        .expect_commit_success();
+    env.execute_with_signatures(operation_hash, vec![bob.2.clone()], signature)
+        .expect_commit_failure();
+
+    // Carol signs, the operation is not executed without Bob
+    env.mint_member_badge(&env.carol.clone(), "add dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+}
+
+#[test]
+fn test_bls_restrictions() {
+    let mut env = Helper::new_3_of_3();
+    let dave_addr = env.dave.0;
+    let (bob, carol) = (env.bob.clone(), env.carol.clone());
+    let bob_key = register_key(&mut env, &bob, 2);
+
+    // A key can't be registered without proving the possession of the private key
+    let carol_key = Bls12381G1PrivateKey::from_u64(3).unwrap();
+    let carol_pk = carol_key.public_key();
+    env.register_bls_key(&carol, carol_pk, bob_key.sign_v1(&carol_pk.0)).expect_commit_failure();
+
+    // The operation must exist
+    let operation_hash = add_dave_hash(&mut env);
+    let signature = aggregate(&[&bob_key], operation_hash);
+    env.execute_with_signatures(operation_hash, vec![bob.2.clone()], signature)
+        .expect_commit_failure();
+
+    // Carol has no key, and the signature must be of the right message
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let message = env.get_bls_message(operation_hash);
+    let signature = aggregate(&[&bob_key, &carol_key], message);
+    env.execute_with_signatures(operation_hash, vec![bob.2.clone(), carol.2.clone()], signature)
+        .expect_commit_failure();
+    let signature = aggregate(&[&bob_key], operation_hash);
+    env.execute_with_signatures(operation_hash, vec![bob.2.clone()], signature)
+        .expect_commit_failure();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+}
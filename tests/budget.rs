@@ -0,0 +1,71 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const DAY: i64 = 86400;
+
+fn grant_bob_budget(env: &mut Helper, amount: Decimal, expiration: Instant) {
+    let bob_id = env.bob.2.clone();
+    env.grant_budget(&env.alice.clone(), "bob budget", bob_id.clone(), XRD, amount, DAY, expiration)
+        .expect_commit_success();
+    let r = env.grant_budget(&env.carol.clone(), "bob budget", bob_id, XRD, amount, DAY, expiration);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_spend_budget() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let dave_balance = env.ledger.get_component_balance(dave_addr, XRD);
+    let expiration = env.now().add_days(30).unwrap();
+    grant_bob_budget(&mut env, dec!("100"), expiration);
+
+    // Bob spends alone
+    let r = env.spend_budget(&env.bob.clone(), XRD, dec!("60"), dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "BudgetSpentEvent"));
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), dave_balance + dec!("60"));
+
+    // Only 40 XRD are left for today
+    env.spend_budget(&env.bob.clone(), XRD, dec!("50"), dave_addr).expect_commit_failure();
+
+    // A new period starts tomorrow
+    env.advance_time(DAY);
+    env.spend_budget(&env.bob.clone(), XRD, dec!("50"), dave_addr).expect_commit_success();
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), dave_balance + dec!("110"));
+}
+
+#[test]
+fn test_spend_budget_restrictions() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(30).unwrap();
+
+    // No budget yet
+    env.spend_budget(&env.bob.clone(), XRD, dec!("10"), dave_addr).expect_commit_failure();
+
+    grant_bob_budget(&mut env, dec!("100"), expiration);
+
+    // Alice has no budget
+    env.spend_budget(&env.alice.clone(), XRD, dec!("10"), dave_addr).expect_commit_failure();
+
+    // The budget expires
+    env.advance_time(30 * DAY);
+    env.spend_budget(&env.bob.clone(), XRD, dec!("10"), dave_addr).expect_commit_failure();
+}
+
+#[test]
+fn test_revoke_budget() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(30).unwrap();
+
+    grant_bob_budget(&mut env, dec!("100"), expiration);
+    grant_bob_budget(&mut env, dec!("0"), expiration);
+    env.spend_budget(&env.bob.clone(), XRD, dec!("10"), dave_addr).expect_commit_failure();
+}
@@ -0,0 +1,33 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::{Helper, ValidatorHelper};
+
+#[test]
+fn test_call_method_stake() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let v = ValidatorHelper::new(&mut env);
+    let dao_account = env.dao_account;
+    let withdrawals = vec![(XRD, ResourceSpecifier::Fungible(dec!("100")))];
+
+    // The arguments must be an SBOR-encoded tuple
+    env.call_method(
+        &env.alice.clone(), "stake", v.addr, "stake",
+        scrypto_encode(&dec!("90")).unwrap(), withdrawals.clone(),
+    ).expect_commit_failure();
+
+    let xrd_before = env.xrd_balance(dao_account);
+    let arguments = scrypto_encode(&()).unwrap();
+    env.call_method(
+        &env.alice.clone(), "stake", v.addr, "stake",
+        arguments.clone(), withdrawals.clone(),
+    ).expect_commit_success();
+    env.call_method(
+        &env.bob.clone(), "stake", v.addr, "stake",
+        arguments, withdrawals,
+    ).expect_commit_success();
+
+    // The LSU returned by the validator are in the treasury
+    assert_eq!(xrd_before - env.xrd_balance(dao_account), dec!("100"));
+    assert!(env.ledger.get_component_balance(dao_account, v.lsu_resource) > dec!("0"));
+}
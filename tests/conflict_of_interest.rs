@@ -0,0 +1,71 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+#[test]
+fn test_conflict_of_interest_signature_does_not_count() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let alice_addr = env.alice.0;
+
+    // Alice proposes to pay herself, Bob's signature is not enough
+    env.send_fungibles(&env.alice.clone(), "pay alice", XRD, dec!("10"), alice_addr)
+        .expect_commit_success();
+    let r = env.send_fungibles(&env.bob.clone(), "pay alice", XRD, dec!("10"), alice_addr);
+    r.expect_commit_success();
+    assert!(!has_event(&r, "OperationExecutedEvent"));
+
+    let r = env.send_fungibles(&env.carol.clone(), "pay alice", XRD, dec!("10"), alice_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_conflict_of_interest_refused() {
+    let mut env = Helper::new_with_conflicts_of_interest_refused(2, 3);
+    env.fund_dao();
+    let alice_addr = env.alice.0;
+    let valid_until = env.now().add_days(365).unwrap();
+
+    // Alice can't sign a payment to herself or her own renewal
+    env.send_fungibles(&env.alice.clone(), "pay alice", XRD, dec!("10"), alice_addr)
+        .expect_commit_failure();
+    env.renew_member(&env.alice.clone(), "renew alice", env.alice.2.clone(), valid_until)
+        .expect_commit_failure();
+
+    // The other members can
+    env.send_fungibles(&env.bob.clone(), "pay alice", XRD, dec!("10"), alice_addr)
+        .expect_commit_success();
+    let r = env.send_fungibles(&env.carol.clone(), "pay alice", XRD, dec!("10"), alice_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_disabling_own_badge_is_not_a_conflict_of_interest() {
+    let mut env = Helper::new_with_conflicts_of_interest_refused(2, 3);
+    let bob_id = env.bob.2.clone();
+
+    // Bob agrees to step down, his signature is accepted and counts
+    env.disable_member_badge(&env.alice.clone(), "disable bob", bob_id.clone())
+        .expect_commit_success();
+    let r = env.disable_member_badge(&env.bob.clone(), "disable bob", bob_id);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_register_account() {
+    let mut env = Helper::new_with_conflicts_of_interest_refused(2, 3);
+    env.fund_dao();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let bob_pk = env.bob.1;
+
+    // Only the owner of an account can register it
+    env.register_account(&env.alice.clone(), dave_addr, &bob_pk).expect_commit_failure();
+
+    env.register_account(&env.alice.clone(), dave_addr, &dave_pk).expect_commit_success();
+    env.send_fungibles(&env.alice.clone(), "pay dave", XRD, dec!("10"), dave_addr)
+        .expect_commit_failure();
+}
@@ -0,0 +1,112 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::{Helper, NewResource, ResourceAction};
+
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData)]
+struct DaoNonFungible {
+    #[mutable]
+    name: String,
+    #[mutable]
+    description: String,
+    #[mutable]
+    key_image_url: UncheckedUrl,
+}
+
+#[test]
+fn test_create_fungible_resource() {
+    let mut env = Helper::new_2_of_3();
+    let (dao_account, dave_addr) = (env.dao_account, env.dave.0);
+
+    // A divisibility greater than 18 is refused
+    let wrong = NewResource::Fungible(19, dec!("1000"), vec![]);
+    env.create_resource(&env.alice.clone(), "wrong", wrong)
+        .expect_commit_failure();
+
+    // Create the community token with its initial supply in the treasury
+    let new_resource = NewResource::Fungible(
+        18,
+        dec!("1000"),
+        vec![("symbol".to_owned(), MetadataValue::String("COMM".to_owned()))],
+    );
+    env.create_resource(&env.alice.clone(), "token", new_resource.clone())
+        .expect_commit_success();
+    let token = env.create_resource(&env.bob.clone(), "token", new_resource)
+        .expect_commit_success().new_resource_addresses()[0];
+    assert_eq!(env.balance(dao_account, token), dec!("1000"));
+    assert_eq!(
+        env.ledger.get_metadata(token.into(), "symbol"),
+        Some(MetadataValue::String("COMM".to_owned())),
+    );
+
+    // The DAO can mint and burn it without any badge
+    let mint = ResourceAction::MintFungibles(dec!("100"), dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", token, None, mint.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "mint", token, None, mint)
+        .expect_commit_success();
+    assert_eq!(env.balance(dave_addr, token), dec!("100"));
+    let burn = ResourceAction::BurnFungibles(dec!("10"));
+    env.manage_resource(&env.alice.clone(), "burn", token, None, burn.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "burn", token, None, burn)
+        .expect_commit_success();
+    assert_eq!(env.balance(dao_account, token), dec!("990"));
+}
+
+#[test]
+fn test_create_non_fungible_resource() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+
+    // Create an empty collection
+    let new_resource = NewResource::NonFungible(
+        vec![("name".to_owned(), MetadataValue::String("DAO collection".to_owned()))],
+    );
+    env.create_resource(&env.alice.clone(), "collection", new_resource.clone())
+        .expect_commit_success();
+    let collection = env.create_resource(&env.bob.clone(), "collection", new_resource)
+        .expect_commit_success().new_resource_addresses()[0];
+
+    // Mint an item to dave without any badge
+    let id = NonFungibleLocalId::integer(1);
+    let data = scrypto_encode(&DaoNonFungible {
+        name: "Founder".to_owned(),
+        description: "Founding member".to_owned(),
+        key_image_url: UncheckedUrl::of("https://example.com/founder.png"),
+    }).unwrap();
+    let mint = ResourceAction::MintNonFungible(id.clone(), data, dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", collection, None, mint.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "mint", collection, None, mint)
+        .expect_commit_success();
+    assert_eq!(env.nft_ids(dave_addr, collection), vec![id.clone()]);
+
+    // Update its description
+    let update = ResourceAction::UpdateNonFungibleData(
+        id.clone(), "description".to_owned(), scrypto_encode("Honorary member").unwrap(),
+    );
+    env.manage_resource(&env.alice.clone(), "update", collection, None, update.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "update", collection, None, update)
+        .expect_commit_success();
+    assert_eq!(
+        env.ledger.get_non_fungible_data::<DaoNonFungible>(collection, id).description,
+        "Honorary member",
+    );
+}
+
+#[test]
+fn test_manage_without_badge_only_created_resources() {
+    let mut env = Helper::new_2_of_3();
+    let (dao_account, dave_addr) = (env.dao_account, env.dave.0);
+    let token = env.ledger.create_fungible_resource(dec!("1000"), 18, dao_account);
+
+    // A resource not created by a CreateResource operation needs a badge
+    let mint = ResourceAction::MintFungibles(dec!("100"), dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", token, None, mint).expect_commit_failure();
+    let metadata = ResourceAction::SetMetadata(
+        "name".to_owned(), MetadataValue::String("Not ours".to_owned()),
+    );
+    env.manage_resource(&env.alice.clone(), "rename", XRD, None, metadata)
+        .expect_commit_failure();
+}
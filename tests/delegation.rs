@@ -0,0 +1,75 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const DAY: i64 = 86400;
+
+#[test]
+fn test_delegated_signature_counts_twice() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(30).unwrap();
+
+    // Carol goes on leave and delegates to Alice
+    env.delegate_signature(&env.carol.clone(), env.alice.2.clone(), expiration, None)
+        .expect_commit_success();
+
+    // Alice alone is enough
+    let r = env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_delegation_restricted_to_operation_types() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(30).unwrap();
+
+    // Carol delegates to Alice for SendFungibles only
+    env.delegate_signature(&env.carol.clone(), env.alice.2.clone(), expiration, Some(vec![5]))
+        .expect_commit_success();
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+
+    let r = env.send_fungibles(&env.alice.clone(), "pay dave", XRD, dec!("10"), dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_revoked_and_expired_delegations() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(1).unwrap();
+
+    // A revoked delegation doesn't count
+    env.delegate_signature(&env.carol.clone(), env.alice.2.clone(), expiration, None)
+        .expect_commit_success();
+    env.revoke_delegation(&env.carol.clone()).expect_commit_success();
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+
+    // Neither does an expired one
+    env.delegate_signature(&env.carol.clone(), env.bob.2.clone(), expiration, None)
+        .expect_commit_success();
+    env.advance_time(DAY);
+    env.mint_member_badge(&env.bob.clone(), "another dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+}
+
+#[test]
+fn test_invalid_delegations() {
+    let mut env = Helper::new_2_of_3();
+    let expiration = env.now().add_days(30).unwrap();
+
+    // Delegating to oneself or to a non member is refused
+    env.delegate_signature(&env.carol.clone(), env.carol.2.clone(), expiration, None)
+        .expect_commit_failure();
+    env.delegate_signature(&env.carol.clone(), NonFungibleLocalId::integer(4), expiration, None)
+        .expect_commit_failure();
+}
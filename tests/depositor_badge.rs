@@ -0,0 +1,57 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+fn add_authorized_depositor(env: &mut Helper, account: ComponentAddress, pk: Secp256k1PublicKey) {
+    let depositor_badge = env.get_depositor_badge();
+    env.ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    account,
+                    ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT,
+                    AccountAddAuthorizedDepositorInput {
+                        badge: ResourceOrNonFungible::Resource(depositor_badge),
+                    },
+                )
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(pk)],
+        )
+        .expect_commit_success();
+}
+
+#[test]
+fn test_authorized_depositor_receives_payments() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+    add_authorized_depositor(&mut env, dave_addr, dave_pk);
+    let before = env.xrd_balance(dave_addr);
+
+    // The payment is delivered instead of being stored in the AccountLocker
+    env.send_fungibles(&env.alice.clone(), "pay dave", XRD, dec!("100"), dave_addr)
+        .expect_commit_success();
+    let r = env.send_fungibles(&env.bob.clone(), "pay dave", XRD, dec!("100"), dave_addr);
+    r.expect_commit_success();
+    assert!(!has_event(&r, "PaymentStoredEvent"));
+    assert_eq!(env.xrd_balance(dave_addr) - before, dec!("100"));
+}
+
+#[test]
+fn test_authorized_depositor_receives_member_badge() {
+    let mut env = Helper::new_2_of_3();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+
+    // Without the depositor badge the new member badge can't be deposited
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_failure();
+
+    add_authorized_depositor(&mut env, dave_addr, dave_pk);
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
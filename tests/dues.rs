@@ -0,0 +1,58 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const DUES_PERIOD: i64 = 30 * 86400;
+const GRACE_PERIOD: i64 = 7 * 86400;
+
+#[test]
+fn test_pay_dues() {
+    let mut env = Helper::new_with_dues(2, 3, (XRD, dec!("10"), DUES_PERIOD), GRACE_PERIOD);
+    let alice_balance = env.ledger.get_component_balance(env.alice.0, XRD);
+
+    // Two periods are paid, the exceeding 5 XRD are given back
+    let r = env.pay_dues(&env.alice.clone(), XRD, dec!("25"));
+    r.expect_commit_success();
+    let (_, paid_until): (Bucket, Instant) = r.expect_commit(true).output(5);
+    assert!(paid_until.seconds_since_unix_epoch >= 2 * DUES_PERIOD);
+    assert_eq!(env.ledger.get_component_balance(env.dao_account, XRD), dec!("20"));
+    assert_eq!(env.ledger.get_component_balance(env.alice.0, XRD), alice_balance - dec!("20"));
+
+    // Less than the dues amount
+    env.pay_dues(&env.alice.clone(), XRD, dec!("5")).expect_commit_failure();
+}
+
+#[test]
+fn test_disable_overdue_members() {
+    let mut env = Helper::new_with_dues(2, 3, (XRD, dec!("10"), DUES_PERIOD), GRACE_PERIOD);
+
+    env.pay_dues(&env.alice.clone(), XRD, dec!("10")).expect_commit_success();
+    env.pay_dues(&env.bob.clone(), XRD, dec!("10")).expect_commit_success();
+
+    // Still in the grace period
+    env.advance_time(GRACE_PERIOD - 60);
+    let r = env.disable_overdue_members();
+    r.expect_commit_success();
+    assert!(!has_event(&r, "MemberOverdueEvent"));
+
+    // Carol didn't pay
+    env.advance_time(120);
+    let r = env.disable_overdue_members();
+    r.expect_commit_success();
+    assert!(has_event(&r, "MemberOverdueEvent"));
+    env.disable_member_badge(&env.alice.clone(), "disable carol", env.carol.2.clone())
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_disable_overdue_members_keeps_min_cosigners_enabled() {
+    let mut env = Helper::new_with_dues(2, 3, (XRD, dec!("10"), DUES_PERIOD), GRACE_PERIOD);
+
+    // Nobody paid, only one badge can be disabled
+    env.advance_time(GRACE_PERIOD + 1);
+    let r = env.disable_overdue_members();
+    r.expect_commit_success();
+    let disabled: Vec<NonFungibleLocalId> = r.expect_commit(true).output(1);
+    assert_eq!(disabled.len(), 1);
+}
@@ -4,6 +4,77 @@ use scrypto::prelude::*;
 use scrypto::prelude::Runtime;
 use scrypto_test::prelude::*;
 
+/// Number of seconds a membership application stays valid.
+pub const APPLICATION_PERIOD: i64 = 7 * 86400;
+
+/// Manifest counterpart of the Action enum of the blueprint, the steps of a Batch operation.
+#[derive(ManifestSbor, Clone)]
+pub enum Action {
+    SendFungibles(ResourceAddress, Decimal, ComponentAddress),
+    SendNonFungibles(ResourceAddress, Vec<NonFungibleLocalId>, ComponentAddress),
+    Stake(Decimal, ComponentAddress),
+    Unstake(Decimal, ComponentAddress),
+    DisableBadge(NonFungibleLocalId),
+    EnableBadge(NonFungibleLocalId),
+    RenewMember(NonFungibleLocalId, Instant),
+}
+
+/// Manifest counterpart of the ResourceAction enum of the blueprint, the action of a
+/// ManageResource operation.
+#[derive(ManifestSbor, Clone)]
+pub enum ResourceAction {
+    MintFungibles(Decimal, ComponentAddress),
+    MintNonFungible(NonFungibleLocalId, Vec<u8>, ComponentAddress),
+    BurnFungibles(Decimal),
+    BurnNonFungibles(Vec<NonFungibleLocalId>),
+    UpdateNonFungibleData(NonFungibleLocalId, String, Vec<u8>),
+    SetMetadata(String, MetadataValue),
+}
+
+/// Manifest counterpart of the NewResource enum of the blueprint, the resource created by a
+/// CreateResource operation.
+#[derive(ManifestSbor, Clone)]
+pub enum NewResource {
+    Fungible(u8, Decimal, Vec<(String, MetadataValue)>),
+    NonFungible(Vec<(String, MetadataValue)>),
+}
+
+/// Manifest counterpart of the OperationDetails enum of the blueprint, the type and parameters
+/// of an operation.
+#[derive(ManifestSbor, Clone)]
+pub enum OperationDetails {
+    MintBadge(ComponentAddress),
+    DisableBadge(NonFungibleLocalId),
+    EnableBadge(NonFungibleLocalId),
+    IncreaseMinCosigners,
+    DecreaseMinCosigners,
+    SendFungibles(ResourceAddress, Decimal, ComponentAddress),
+    SendNonFungibles(ResourceAddress, Vec<NonFungibleLocalId>, ComponentAddress),
+    TransferAccountBadge(ComponentAddress),
+    Stake(Decimal, ComponentAddress),
+    Unstake(Decimal, ComponentAddress),
+    ClaimUnstakedXrd(Vec<NonFungibleLocalId>, ComponentAddress),
+    SetMemberBadgeMetadata(String, MetadataValue),
+    RenewMember(NonFungibleLocalId, Instant),
+    DisableInactiveMembers(i64, Vec<NonFungibleLocalId>),
+    InviteMember(ComponentAddress, Instant),
+    RevokeInvitation(ComponentAddress),
+    ApproveApplication(ComponentAddress),
+    RejectApplication(ComponentAddress),
+    GrantBudget(NonFungibleLocalId, ResourceAddress, Decimal, i64, Instant),
+    GrantAllowance(ComponentAddress, ResourceAddress, Decimal, i64, Instant),
+    RevokeAllowance(NonFungibleLocalId),
+    MintKeylessBadge(NonFungibleGlobalId),
+    Batch(Vec<Action>),
+    SendFungiblesMulti(ResourceAddress, Vec<(ComponentAddress, Decimal)>),
+    CallMethod(ComponentAddress, String, Vec<u8>, Vec<(ResourceAddress, ResourceSpecifier)>),
+    Swap(ComponentAddress, ResourceAddress, Decimal, ResourceAddress, Decimal),
+    ContributeToPool(ComponentAddress, Vec<(ResourceAddress, Decimal)>),
+    RedeemFromPool(ComponentAddress, Decimal),
+    ManageResource(ResourceAddress, Option<(ResourceAddress, ResourceSpecifier)>, ResourceAction),
+    CreateResource(NewResource),
+}
+
 pub struct Helper {
     pub ledger:       DefaultLedgerSimulator,
 
@@ -16,6 +87,7 @@ pub struct Helper {
     pub package:      PackageAddress,
     pub component:    ComponentAddress,
     pub member_badge: ResourceAddress,
+    pub allowance_badge: ResourceAddress,
     /// The internal Account that holds the DAO treasury.
     pub dao_account:  ComponentAddress,
 }
@@ -28,6 +100,83 @@ impl Helper {
     pub fn new(
         min_cosigners: usize,
         members_number: usize,
+    ) -> Self {
+        Self::new_with_probation(min_cosigners, members_number, 0)
+    }
+
+    pub fn new_with_probation(
+        min_cosigners: usize,
+        members_number: usize,
+        probation_period: i64,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, probation_period, None, Decimal::ZERO, false, None, 0, false, false,
+        )
+    }
+
+    pub fn new_with_term(
+        min_cosigners: usize,
+        members_number: usize,
+        member_term: i64,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, 0, Some(member_term), Decimal::ZERO, false, None, 0, false, false,
+        )
+    }
+
+    pub fn new_with_application_bond(
+        min_cosigners: usize,
+        members_number: usize,
+        application_bond: Decimal,
+        forfeit_bonds: bool,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, 0, None, application_bond, forfeit_bonds, None, 0, false, false,
+        )
+    }
+
+    pub fn new_with_dues(
+        min_cosigners: usize,
+        members_number: usize,
+        dues: (ResourceAddress, Decimal, i64),
+        dues_grace_period: i64,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, 0, None, Decimal::ZERO, false, Some(dues),
+            dues_grace_period, false, false,
+        )
+    }
+
+    pub fn new_with_conflicts_of_interest_refused(
+        min_cosigners: usize,
+        members_number: usize,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, 0, None, Decimal::ZERO, false, None, 0, true, false,
+        )
+    }
+
+    pub fn new_with_refused_deposits_aborted(
+        min_cosigners: usize,
+        members_number: usize,
+    ) -> Self {
+        Self::new_with_options(
+            min_cosigners, members_number, 0, None, Decimal::ZERO, false, None, 0, false, true,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_options(
+        min_cosigners: usize,
+        members_number: usize,
+        probation_period: i64,
+        member_term: Option<i64>,
+        application_bond: Decimal,
+        forfeit_bonds: bool,
+        dues: Option<(ResourceAddress, Decimal, i64)>,
+        dues_grace_period: i64,
+        refuse_conflicts_of_interest: bool,
+        abort_refused_deposits: bool,
     ) -> Self {
         let mut ledger = LedgerSimulatorBuilder::new().build();
 
@@ -45,7 +194,14 @@ impl Helper {
         };
 
         // Instantiate DaoWallet.
-        // Blueprint: new(members: IndexSet<Global<Account>>, min_cosigners: usize)
+        // Blueprint: new(members: IndexSet<Global<Account>>, min_cosigners: usize, name: String,
+        //                symbol: String, badge_description: String, icon_url: String,
+        //                tags: Vec<String>, dapp_definition: Option<GlobalAddress>,
+        //                probation_period: i64, member_term: Option<i64>,
+        //                application_bond: Decimal, application_period: i64,
+        //                forfeit_bonds: bool, dues: Option<(ResourceAddress, Decimal, i64)>,
+        //                dues_grace_period: i64, refuse_conflicts_of_interest: bool,
+        //                abort_refused_deposits: bool)
         // Returns: (Global<DaoWallet>, ResourceAddress, Global<Account>)
         // In manifests IndexSet<Global<Account>> serialises as Array<Address>.
         let receipt = ledger.execute_manifest(
@@ -57,11 +213,26 @@ impl Helper {
                     "new",
                     manifest_args!(
                         members,
-                        min_cosigners
+                        min_cosigners,
+                        "DAO wallet badge",
+                        "DAOW",
+                        "Member badge of the test DAO",
+                        "https://example.com/badge.png",
+                        vec!["dao"],
+                        None::<ComponentAddress>,
+                        probation_period,
+                        member_term,
+                        application_bond,
+                        APPLICATION_PERIOD,
+                        forfeit_bonds,
+                        dues,
+                        dues_grace_period,
+                        refuse_conflicts_of_interest,
+                        abort_refused_deposits
                     ),
                 )
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&alice_pk)],
+            vec![NonFungibleGlobalId::from_public_key(alice_pk)],
         );
         receipt.expect_commit_success();
 
@@ -69,6 +240,7 @@ impl Helper {
         let component    = commit.new_component_addresses()[0];
         let dao_account  = commit.new_component_addresses()[1];
         let member_badge = commit.new_resource_addresses()[0];
+        let allowance_badge = commit.new_resource_addresses()[1];
 
         // Badge assignment is REVERSED (IndexSet::pop removes last-inserted):
         //   Insertion order: alice(0), bob(1), carol(2)
@@ -82,6 +254,7 @@ impl Helper {
             package,
             component,
             member_badge,
+            allowance_badge,
             dao_account,
         }
     }
@@ -204,6 +377,179 @@ impl Helper {
         self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
     }
 
+    pub fn send_fungibles_multi(
+        &mut self,
+        caller:   &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:     &str,
+        resource: ResourceAddress,
+        payments: Vec<(ComponentAddress, Decimal)>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "send_fungibles_multi", |l| {
+                (l.proof("p"), desc.to_owned(), resource, payments)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn call_method(
+        &mut self,
+        caller:      &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:        &str,
+        component:   ComponentAddress,
+        method:      &str,
+        arguments:   Vec<u8>,
+        withdrawals: Vec<(ResourceAddress, ResourceSpecifier)>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "call_method", |l| {
+                (l.proof("p"), desc.to_owned(), component, method.to_owned(), arguments, withdrawals)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap(
+        &mut self,
+        caller:          &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:            &str,
+        pool:            ComponentAddress,
+        input_resource:  ResourceAddress,
+        input_amount:    Decimal,
+        output_resource: ResourceAddress,
+        min_output:      Decimal,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "swap", |l| {
+                (
+                    l.proof("p"), desc.to_owned(), pool, input_resource, input_amount,
+                    output_resource, min_output,
+                )
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn contribute_to_pool(
+        &mut self,
+        caller:        &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:          &str,
+        pool:          ComponentAddress,
+        contributions: Vec<(ResourceAddress, Decimal)>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "contribute_to_pool", |l| {
+                (l.proof("p"), desc.to_owned(), pool, contributions)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn redeem_from_pool(
+        &mut self,
+        caller: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:   &str,
+        pool:   ComponentAddress,
+        amount: Decimal,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "redeem_from_pool", |l| {
+                (l.proof("p"), desc.to_owned(), pool, amount)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn manage_resource(
+        &mut self,
+        caller:          &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:            &str,
+        resource:        ResourceAddress,
+        badge:           Option<(ResourceAddress, ResourceSpecifier)>,
+        resource_action: ResourceAction,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "manage_resource", |l| {
+                (l.proof("p"), desc.to_owned(), resource, badge, resource_action)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn create_resource(
+        &mut self,
+        caller:       &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:         &str,
+        new_resource: NewResource,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "create_resource", |l| {
+                (l.proof("p"), desc.to_owned(), new_resource)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    /// Instantiate an empty native pool whose contribute method anyone can call; returns the
+    /// pool and its pool unit resource.
+    pub fn new_native_pool<A: ManifestEncode>(
+        &mut self,
+        blueprint: &str,
+        resources: A,
+    ) -> (ComponentAddress, ResourceAddress) {
+        let commit = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    POOL_PACKAGE,
+                    blueprint,
+                    "instantiate",
+                    manifest_args!(
+                        OwnerRole::None, rule!(allow_all), resources, None::<ManifestAddressReservation>,
+                    ),
+                )
+                .build(),
+            vec![],
+        ).expect_commit(true).clone();
+        (commit.new_component_addresses()[0], commit.new_resource_addresses()[0])
+    }
+
     pub fn send_non_fungibles(
         &mut self,
         caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
@@ -225,6 +571,99 @@ impl Helper {
         self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
     }
 
+    /// Several members create/sign an operation in a single transaction.
+    pub fn sign_operation(
+        &mut self,
+        callers: &[&(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId)],
+        desc:    &str,
+        details: OperationDetails,
+    ) -> TransactionReceiptV1 {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for (n, caller) in callers.iter().enumerate() {
+            builder = builder
+                .create_proof_from_account_of_non_fungibles(
+                    caller.0, self.member_badge, indexset![caller.2.clone()],
+                )
+                .pop_from_auth_zone(format!("p{}", n));
+        }
+        let manifest = builder
+            .call_method_with_name_lookup(self.component, "sign_operation", |l| {
+                (
+                    (0..callers.len()).map(|n| l.proof(format!("p{}", n))).collect::<Vec<_>>(),
+                    desc.to_owned(),
+                    details,
+                )
+            })
+            .build();
+        let signers: Vec<NonFungibleGlobalId> = callers
+            .iter()
+            .map(|caller| NonFungibleGlobalId::from_public_key(caller.1))
+            .collect();
+        self.ledger.execute_manifest(manifest, signers)
+    }
+
+    /// Several members create/sign an operation with their badges in the auth zone.
+    pub fn sign_operation_with_auth_zone(
+        &mut self,
+        callers: &[&(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId)],
+        desc:    &str,
+        details: OperationDetails,
+    ) -> TransactionReceiptV1 {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for caller in callers.iter() {
+            builder = builder.create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            );
+        }
+        let badge_ids: Vec<NonFungibleLocalId> = callers
+            .iter()
+            .map(|caller| caller.2.clone())
+            .collect();
+        let manifest = builder
+            .call_method(
+                self.component,
+                "sign_operation_with_auth_zone",
+                manifest_args!(
+                    badge_ids,
+                    desc.to_owned(),
+                    details,
+                ),
+            )
+            .build();
+        let signers: Vec<NonFungibleGlobalId> = callers
+            .iter()
+            .map(|caller| NonFungibleGlobalId::from_public_key(caller.1))
+            .collect();
+        self.ledger.execute_manifest(manifest, signers)
+    }
+
+    /// A keyless member creates/signs an operation by signing the transaction with his key.
+    pub fn sign_operation_as_keyless(
+        &mut self,
+        pk:       &Secp256k1PublicKey,
+        badge_id: NonFungibleLocalId,
+        desc:     &str,
+        details:  OperationDetails,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "sign_operation_with_auth_zone",
+                manifest_args!(
+                    vec![badge_id],
+                    desc.to_owned(),
+                    details,
+                ),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    /// Removes a signature describing the operation with the positional arguments the
+    /// `remove_signature` method took before `OperationDetails`; only the operation types that
+    /// existed back then (0 to 10) can be described this way.
+    #[allow(clippy::too_many_arguments)]
     pub fn remove_signature(
         &mut self,
         caller:              &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
@@ -236,6 +675,33 @@ impl Helper {
         recipient_account:   Option<ComponentAddress>,
         recipient_component: Option<ComponentAddress>,
         validator:           Option<ComponentAddress>,
+    ) -> TransactionReceiptV1 {
+        let details = match op_type {
+            0 => OperationDetails::MintBadge(recipient_account.unwrap()),
+            1 => OperationDetails::DisableBadge(nft_ids.unwrap().remove(0)),
+            2 => OperationDetails::EnableBadge(nft_ids.unwrap().remove(0)),
+            3 => OperationDetails::IncreaseMinCosigners,
+            4 => OperationDetails::DecreaseMinCosigners,
+            5 => OperationDetails::SendFungibles(
+                resource_address.unwrap(), fungibles_amount.unwrap(), recipient_account.unwrap(),
+            ),
+            6 => OperationDetails::SendNonFungibles(
+                resource_address.unwrap(), nft_ids.unwrap(), recipient_account.unwrap(),
+            ),
+            7 => OperationDetails::TransferAccountBadge(recipient_component.unwrap()),
+            8 => OperationDetails::Stake(fungibles_amount.unwrap(), validator.unwrap()),
+            9 => OperationDetails::Unstake(fungibles_amount.unwrap(), validator.unwrap()),
+            10 => OperationDetails::ClaimUnstakedXrd(nft_ids.unwrap(), validator.unwrap()),
+            _ => panic!("Use remove_signature_with_details for operation type {}", op_type),
+        };
+        self.remove_signature_with_details(caller, desc, details)
+    }
+
+    pub fn remove_signature_with_details(
+        &mut self,
+        caller:  &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:    &str,
+        details: OperationDetails,
     ) -> TransactionReceiptV1 {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -247,19 +713,32 @@ impl Helper {
                 (
                     l.proof("p"),
                     desc.to_owned(),
-                    op_type,
-                    resource_address,
-                    fungibles_amount,
-                    nft_ids,
-                    recipient_account,
-                    recipient_component,
-                    validator,
+                    details,
                 )
             })
             .build();
         self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
     }
 
+    /// A keyless member removes his signature by signing the transaction with his key.
+    pub fn remove_signature_as_keyless(
+        &mut self,
+        pk:       &Secp256k1PublicKey,
+        badge_id: NonFungibleLocalId,
+        desc:     &str,
+        details:  OperationDetails,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "remove_signature_with_auth_zone",
+                manifest_args!(badge_id, desc.to_owned(), details),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
     pub fn dao_stake(
         &mut self,
         caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
@@ -277,7 +756,7 @@ impl Helper {
                 (l.proof("p"), desc.to_owned(), amount, validator)
             })
             .build();
-        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&caller.1)])
+        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(caller.1)])
     }
 
     pub fn dao_unstake(
@@ -297,7 +776,7 @@ impl Helper {
                 (l.proof("p"), desc.to_owned(), amount, validator)
             })
             .build();
-        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&caller.1)])
+        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(caller.1)])
     }
 
     pub fn dao_claim_unstaked_xrd(
@@ -317,7 +796,7 @@ impl Helper {
                 (l.proof("p"), desc.to_owned(), claim_ids, validator)
             })
             .build();
-        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&caller.1)])
+        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(caller.1)])
     }
 
     pub fn transfer_account_badge(
@@ -336,43 +815,769 @@ impl Helper {
                 (l.proof("p"), desc.to_owned(), component)
             })
             .build();
-        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&caller.1)])
+        self.ledger.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(caller.1)])
     }
 
-    pub fn nft_count(&mut self, account: ComponentAddress, resource: ResourceAddress) -> usize {
-        self.nft_ids(account, resource).len()
+    pub fn set_member_badge_metadata(
+        &mut self,
+        caller: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:   &str,
+        key:    &str,
+        value:  MetadataValue,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "set_member_badge_metadata", |l| {
+                (l.proof("p"), desc.to_owned(), key.to_owned(), value)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
     }
 
-    pub fn nft_ids(
+    pub fn renew_member(
         &mut self,
-        account:  ComponentAddress,
-        resource: ResourceAddress,
-    ) -> Vec<NonFungibleLocalId> {
-        let receipt = self.ledger.execute_manifest(
-            ManifestBuilder::new()
-                .lock_fee_from_faucet()
-                .call_method(account, "non_fungible_local_ids", manifest_args!(resource, 200u32))
-                .build(),
-            vec![],
-        );
-        receipt
-            .expect_commit(true)
-            .outcome
-            .expect_success()
-            .get(1)
-            .and_then(|output| match output {
-                InstructionOutput::CallReturn(bytes) =>
-                    scrypto_decode::<Vec<NonFungibleLocalId>>(bytes).ok(),
-                _ => None,
+        caller:      &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:        &str,
+        target_id:   NonFungibleLocalId,
+        valid_until: Instant,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "renew_member", |l| {
+                (l.proof("p"), desc.to_owned(), target_id, valid_until)
             })
-            .unwrap_or_default()
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
     }
 
-    pub fn xrd_balance(&mut self, account: ComponentAddress) -> Decimal {
-        self.ledger.get_component_balance(account, XRD)
+    pub fn expire_members(&mut self) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "expire_members", manifest_args!())
+            .build();
+        self.ledger.execute_manifest(manifest, vec![])
+    }
+
+    pub fn disable_inactive_members(
+        &mut self,
+        caller: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:   &str,
+        period: i64,
+        ids:    Vec<NonFungibleLocalId>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "disable_inactive_members", |l| {
+                (l.proof("p"), desc.to_owned(), period, ids)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn get_inactive_members(&mut self, period: i64) -> Vec<(NonFungibleLocalId, Instant)> {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_inactive_members", manifest_args!(period))
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    pub fn invite_member(
+        &mut self,
+        caller:     &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:       &str,
+        new_member: ComponentAddress,
+        expiration: Instant,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "invite_member", |l| {
+                (l.proof("p"), desc.to_owned(), new_member, expiration)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn revoke_invitation(
+        &mut self,
+        caller:     &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:       &str,
+        new_member: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "revoke_invitation", |l| {
+                (l.proof("p"), desc.to_owned(), new_member)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    /// Claims the invitation of `account` signing with `pk`.
+    pub fn claim_invitation(
+        &mut self,
+        account: ComponentAddress,
+        pk:      &Secp256k1PublicKey,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "claim_invitation", manifest_args!(account))
+            .deposit_entire_worktop(account)
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    pub fn expire_invitation(&mut self, account: ComponentAddress) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "expire_invitation", manifest_args!(account))
+            .build();
+        self.ledger.execute_manifest(manifest, vec![])
+    }
+
+    pub fn get_invitation(&mut self, account: ComponentAddress) -> Option<Option<Instant>> {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_invitation", manifest_args!(account))
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    /// Applies for membership from `account` signing with `pk`, locking `bond` XRD (if any).
+    pub fn apply_for_membership(
+        &mut self,
+        account: ComponentAddress,
+        pk:      &Secp256k1PublicKey,
+        message: &str,
+        bond:    Option<Decimal>,
+    ) -> TransactionReceiptV1 {
+        let manifest = match bond {
+            Some(bond) => ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .withdraw_from_account(account, XRD, bond)
+                .take_all_from_worktop(XRD, "bond")
+                .call_method_with_name_lookup(self.component, "apply_for_membership", |l| {
+                    (account, message.to_owned(), Some(l.bucket("bond")))
+                })
+                .build(),
+            None => ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.component,
+                    "apply_for_membership",
+                    manifest_args!(account, message.to_owned(), None::<ManifestBucket>),
+                )
+                .build(),
+        };
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    pub fn get_application(
+        &mut self,
+        candidate: ComponentAddress,
+    ) -> Option<(String, Decimal, Instant)> {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_application", manifest_args!(candidate))
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    pub fn approve_application(
+        &mut self,
+        caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:      &str,
+        candidate: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        self.application_operation(caller, "approve_application", desc, candidate)
+    }
+
+    pub fn reject_application(
+        &mut self,
+        caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:      &str,
+        candidate: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        self.application_operation(caller, "reject_application", desc, candidate)
+    }
+
+    fn application_operation(
+        &mut self,
+        caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        method:    &str,
+        desc:      &str,
+        candidate: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, method, |l| {
+                (l.proof("p"), desc.to_owned(), candidate)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn expire_application(&mut self, candidate: ComponentAddress) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "expire_application", manifest_args!(candidate))
+            .build();
+        self.ledger.execute_manifest(manifest, vec![])
+    }
+
+    pub fn pay_dues(
+        &mut self,
+        caller:   &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        resource: ResourceAddress,
+        amount:   Decimal,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .withdraw_from_account(caller.0, resource, amount)
+            .take_all_from_worktop(resource, "dues")
+            .call_method_with_name_lookup(self.component, "pay_dues", |l| {
+                (l.proof("p"), l.bucket("dues"))
+            })
+            .deposit_entire_worktop(caller.0)
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn disable_overdue_members(&mut self) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "disable_overdue_members", manifest_args!())
+            .build();
+        self.ledger.execute_manifest(manifest, vec![])
+    }
+
+    /// Registers `account` for the member badge of `caller`; `account_pk` signs as owner.
+    pub fn register_account(
+        &mut self,
+        caller:     &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        account:    ComponentAddress,
+        account_pk: &Secp256k1PublicKey,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "register_account", |l| {
+                (l.proof("p"), account)
+            })
+            .build();
+        self.ledger.execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(caller.1),
+                NonFungibleGlobalId::from_public_key(*account_pk),
+            ],
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn grant_budget(
+        &mut self,
+        caller:     &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:       &str,
+        badge_id:   NonFungibleLocalId,
+        resource:   ResourceAddress,
+        amount:     Decimal,
+        period:     i64,
+        expiration: Instant,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "grant_budget", |l| {
+                (l.proof("p"), desc.to_owned(), badge_id, resource, amount, period, expiration)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn spend_budget(
+        &mut self,
+        caller:    &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        resource:  ResourceAddress,
+        amount:    Decimal,
+        recipient: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "spend_budget", |l| {
+                (l.proof("p"), resource, amount, recipient)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn spend_budget_as_keyless(
+        &mut self,
+        pk:        &Secp256k1PublicKey,
+        badge_id:  NonFungibleLocalId,
+        resource:  ResourceAddress,
+        amount:    Decimal,
+        recipient: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "spend_budget_with_auth_zone",
+                manifest_args!(badge_id, resource, amount, recipient),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    /// Pays dues for a keyless member with XRD from the faucet; the change goes to `change_to`.
+    pub fn pay_dues_as_keyless(
+        &mut self,
+        pk:        &Secp256k1PublicKey,
+        badge_id:  NonFungibleLocalId,
+        amount:    Decimal,
+        change_to: ComponentAddress,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, amount, "dues")
+            .call_method_with_name_lookup(self.component, "pay_dues_with_auth_zone", |l| {
+                (badge_id, l.bucket("dues"))
+            })
+            .try_deposit_entire_worktop_or_abort(change_to, None)
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    /// Registers `account` for a keyless member; `account_pk` signs as owner.
+    pub fn register_account_as_keyless(
+        &mut self,
+        pk:         &Secp256k1PublicKey,
+        badge_id:   NonFungibleLocalId,
+        account:    ComponentAddress,
+        account_pk: &Secp256k1PublicKey,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "register_account_with_auth_zone",
+                manifest_args!(badge_id, account),
+            )
+            .build();
+        self.ledger.execute_manifest(
+            manifest,
+            vec![
+                NonFungibleGlobalId::from_public_key(*pk),
+                NonFungibleGlobalId::from_public_key(*account_pk),
+            ],
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn grant_allowance(
+        &mut self,
+        caller:     &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:       &str,
+        holder:     ComponentAddress,
+        resource:   ResourceAddress,
+        amount:     Decimal,
+        period:     i64,
+        expiration: Instant,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "grant_allowance", |l| {
+                (l.proof("p"), desc.to_owned(), holder, resource, amount, period, expiration)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn revoke_allowance(
+        &mut self,
+        caller:       &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:         &str,
+        allowance_id: NonFungibleLocalId,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "revoke_allowance", |l| {
+                (l.proof("p"), desc.to_owned(), allowance_id)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    /// The holder account withdraws from the DAO treasury using an allowance badge.
+    pub fn withdraw_with_allowance(
+        &mut self,
+        holder:       ComponentAddress,
+        holder_pk:    &Secp256k1PublicKey,
+        allowance_id: NonFungibleLocalId,
+        amount:       Decimal,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                holder, self.allowance_badge, indexset![allowance_id],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "withdraw_with_allowance", |l| {
+                (l.proof("p"), amount)
+            })
+            .try_deposit_entire_worktop_or_abort(holder, None)
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(holder_pk))
+    }
+
+    pub fn delegate_signature(
+        &mut self,
+        caller:          &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        delegate:        NonFungibleLocalId,
+        expiration:      Instant,
+        operation_types: Option<Vec<u8>>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "delegate_signature", |l| {
+                (l.proof("p"), delegate, expiration, operation_types)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn revoke_delegation(
+        &mut self,
+        caller: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "revoke_delegation", |l| {
+                (l.proof("p"),)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn delegate_signature_as_keyless(
+        &mut self,
+        pk:              &Secp256k1PublicKey,
+        badge_id:        NonFungibleLocalId,
+        delegate:        NonFungibleLocalId,
+        expiration:      Instant,
+        operation_types: Option<Vec<u8>>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "delegate_signature_with_auth_zone",
+                manifest_args!(badge_id, delegate, expiration, operation_types),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    pub fn revoke_delegation_as_keyless(
+        &mut self,
+        pk:       &Secp256k1PublicKey,
+        badge_id: NonFungibleLocalId,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "revoke_delegation_with_auth_zone",
+                manifest_args!(badge_id),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    /// Current ledger time, rounded to seconds.
+    pub fn mint_keyless_member_badge(
+        &mut self,
+        caller: &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:   &str,
+        signer: NonFungibleGlobalId,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "mint_keyless_member_badge", |l| {
+                (l.proof("p"), desc.to_owned(), signer)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn register_bls_key(
+        &mut self,
+        caller:              &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        public_key:          Bls12381G1PublicKey,
+        proof_of_possession: Bls12381G2Signature,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "register_bls_key", |l| {
+                (l.proof("p"), public_key, proof_of_possession)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn register_bls_key_as_keyless(
+        &mut self,
+        pk:                  &Secp256k1PublicKey,
+        badge_id:            NonFungibleLocalId,
+        public_key:          Bls12381G1PublicKey,
+        proof_of_possession: Bls12381G2Signature,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.component,
+                "register_bls_key_with_auth_zone",
+                manifest_args!(badge_id, public_key, proof_of_possession),
+            )
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(pk))
+    }
+
+    pub fn get_operation_hash(
+        &mut self,
+        desc:    &str,
+        details: OperationDetails,
+    ) -> Hash {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.component,
+                    "get_operation_hash",
+                    manifest_args!(
+                        desc.to_owned(),
+                        details,
+                    ),
+                )
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    pub fn get_account_locker(&mut self) -> ComponentAddress {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_account_locker", manifest_args!())
+                .build(),
+            vec![],
+        );
+        let locker: Global<AccountLocker> = receipt.expect_commit(true).output(1);
+        locker.address()
+    }
+
+    pub fn get_depositor_badge(&mut self) -> ResourceAddress {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_depositor_badge", manifest_args!())
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    pub fn get_bls_message(&mut self, operation_hash: Hash) -> Hash {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_bls_message", manifest_args!(operation_hash))
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit(true).output(1)
+    }
+
+    /// Anyone can submit the aggregate signature, no member badge is needed.
+    pub fn execute_with_signatures(
+        &mut self,
+        operation_hash:      Hash,
+        signers:             Vec<NonFungibleLocalId>,
+        aggregate_signature: Bls12381G2Signature,
+    ) -> TransactionReceiptV1 {
+        self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.component,
+                    "execute_with_signatures",
+                    manifest_args!(operation_hash, signers, aggregate_signature),
+                )
+                .build(),
+            vec![],
+        )
+    }
+
+    pub fn batch(
+        &mut self,
+        caller:  &(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId),
+        desc:    &str,
+        actions: Vec<Action>,
+    ) -> TransactionReceiptV1 {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                caller.0, self.member_badge, indexset![caller.2.clone()],
+            )
+            .pop_from_auth_zone("p")
+            .call_method_with_name_lookup(self.component, "batch", |l| {
+                (l.proof("p"), desc.to_owned(), actions)
+            })
+            .build();
+        self.ledger.execute_manifest(manifest, Self::signer(&caller.1))
+    }
+
+    pub fn now(&mut self) -> Instant {
+        self.ledger.get_current_time(TimePrecision::Second)
+    }
+
+    pub fn nft_count(&mut self, account: ComponentAddress, resource: ResourceAddress) -> usize {
+        self.nft_ids(account, resource).len()
+    }
+
+    pub fn nft_ids(
+        &mut self,
+        account:  ComponentAddress,
+        resource: ResourceAddress,
+    ) -> Vec<NonFungibleLocalId> {
+        let receipt = self.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(account, "non_fungible_local_ids", manifest_args!(resource, 200u32))
+                .build(),
+            vec![],
+        );
+        receipt
+            .expect_commit(true)
+            .outcome
+            .expect_success()
+            .get(1)
+            .and_then(|output| match output {
+                InstructionOutput::CallReturn(bytes) =>
+                    scrypto_decode::<Vec<NonFungibleLocalId>>(bytes).ok(),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Move the ledger clock forward by the specified number of seconds.
+    pub fn advance_time(&mut self, seconds: i64) {
+        let round = self.ledger.get_consensus_manager_state().round.number() + 1;
+        let timestamp_ms = self.ledger.get_current_proposer_timestamp_ms() + seconds * 1000;
+        self.ledger
+            .advance_to_round_at_timestamp(Round::of(round), timestamp_ms)
+            .expect_commit_success();
+    }
+
+    pub fn xrd_balance(&mut self, account: ComponentAddress) -> Decimal {
+        self.ledger.get_component_balance(account, XRD)
+    }
+
+    pub fn balance(&mut self, account: ComponentAddress, resource: ResourceAddress) -> Decimal {
+        self.ledger.get_component_balance(account, resource)
+    }
+
+    /// Deposit XRD from the faucet into the DAO's internal Account.
+    pub fn reject_deposits(&mut self, account: ComponentAddress, pk: Secp256k1PublicKey) {
+        self.ledger
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .call_method(
+                        account,
+                        ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+                        AccountSetDefaultDepositRuleInput { default: DefaultDepositRule::Reject },
+                    )
+                    .build(),
+                Self::signer(&pk),
+            )
+            .expect_commit_success();
     }
 
-    /// Deposit XRD from the faucet into the DAO's internal Account.
     pub fn fund_dao(&mut self) {
         self.ledger.execute_manifest(
             ManifestBuilder::new()
@@ -394,6 +1599,17 @@ pub fn has_event(receipt: &TransactionReceiptV1, name: &str) -> bool {
         .any(|(id, _)| id.1.as_str().contains(name))
 }
 
+/// SBOR encoded data of the first event with the given name.
+pub fn event_data(receipt: &TransactionReceiptV1, name: &str) -> Vec<u8> {
+    receipt
+        .expect_commit(true)
+        .application_events
+        .iter()
+        .find(|(id, _)| id.1.as_str().contains(name))
+        .map(|(_, data)| data.clone())
+        .unwrap()
+}
+
 pub struct ValidatorHelper {
     /// ComponentAddress of the Validator component
     pub addr:             ComponentAddress,
@@ -421,7 +1637,7 @@ impl ValidatorHelper {
                 })
                 .try_deposit_entire_worktop_or_abort(owner_addr, None)
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
         );
         receipt.expect_commit_success();
         let validator_addr = receipt.expect_commit(true).new_component_addresses()[0];
@@ -442,7 +1658,7 @@ impl ValidatorHelper {
                     manifest_args!(true),
                 )
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
         )
         .expect_commit_success();
 
@@ -482,6 +1698,7 @@ pub struct AccountBadgeReceiverHelper {
 }
 
 impl AccountBadgeReceiverHelper {
+    #[allow(clippy::redundant_field_names)]
     pub fn new(env: &mut Helper) -> Self {
         let (owner_pk, _, owner_addr) = env.ledger.new_allocated_account();
 
@@ -497,7 +1714,7 @@ impl AccountBadgeReceiverHelper {
                     manifest_args!(),
                 )
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&owner_pk)],
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
         );
         let component = receipt.expect_commit(true).new_component_addresses()[0];
 
@@ -509,3 +1726,50 @@ impl AccountBadgeReceiverHelper {
         }
     }
 }
+
+pub struct ConstantProductPoolHelper {
+    // Token traded against XRD in the pool; the DAO treasury holds 1000 of them
+    pub token: ResourceAddress,
+    // Package containing the ConstantProductPool blueprint
+    pub package: PackageAddress,
+    // ConstantProductPool component address, it holds 1000 XRD and 1000 tokens
+    pub component: ComponentAddress,
+    // Pool units resource address
+    pub pool_units: ResourceAddress,
+}
+
+impl ConstantProductPoolHelper {
+    #[allow(clippy::redundant_field_names)]
+    pub fn new(env: &mut Helper) -> Self {
+        let (owner_pk, _, owner_addr) = env.ledger.new_allocated_account();
+        let token = env.ledger.create_fungible_resource(dec!("2000"), 18, owner_addr);
+
+        let package = env.ledger.compile_and_publish("constant_product_pool");
+
+        let receipt = env.ledger.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .withdraw_from_account(owner_addr, XRD, dec!("1000"))
+                .withdraw_from_account(owner_addr, token, dec!("2000"))
+                .take_from_worktop(XRD, dec!("1000"), "xrd")
+                .take_from_worktop(token, dec!("1000"), "token")
+                .call_function_with_name_lookup(package, "ConstantProductPool", "new", |l| {
+                    (l.bucket("xrd"), l.bucket("token"))
+                })
+                .try_deposit_entire_worktop_or_abort(env.dao_account, None)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(owner_pk)],
+        );
+        let commit = receipt.expect_commit(true);
+        let component = commit.new_component_addresses()[0];
+        let pool_units = commit.new_resource_addresses()[0];
+
+        // The DAO treasury got the remaining tokens and the initial pool units
+        ConstantProductPoolHelper {
+            token: token,
+            package: package,
+            component: component,
+            pool_units: pool_units,
+        }
+    }
+}
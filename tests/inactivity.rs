@@ -0,0 +1,115 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const INACTIVITY_PERIOD: i64 = 86400;
+
+#[test]
+fn test_inactivity_report() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+
+    assert!(env.get_inactive_members(INACTIVITY_PERIOD).is_empty());
+
+    env.advance_time(INACTIVITY_PERIOD + 1);
+    assert_eq!(env.get_inactive_members(INACTIVITY_PERIOD).len(), 3);
+
+    // Signing an operation makes Alice active again
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let inactive_ids: Vec<NonFungibleLocalId> = env.get_inactive_members(INACTIVITY_PERIOD)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(inactive_ids, vec![env.carol.2.clone(), env.bob.2.clone()]);
+}
+
+#[test]
+fn test_disable_inactive_members() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+
+    // Alice and Bob add Dave, then nobody signs anything for a while
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_success();
+    let dave = (dave_addr, env.dave.1, NonFungibleLocalId::integer(4));
+    env.advance_time(INACTIVITY_PERIOD + 1);
+
+    // Dave proposes something, then Alice and Dave disable Bob and Carol
+    env.mint_member_badge(&dave, "another badge", dave_addr).expect_commit_success();
+    let inactive_ids = vec![env.bob.2.clone(), env.carol.2.clone()];
+    env.disable_inactive_members(
+        &env.alice.clone(), "cleanup", INACTIVITY_PERIOD, inactive_ids.clone(),
+    ).expect_commit_success();
+    let r = env.disable_inactive_members(&dave, "cleanup", INACTIVITY_PERIOD, inactive_ids);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+
+    // Bob and Carol have been disabled
+    env.disable_member_badge(&env.alice.clone(), "disable bob", env.bob.2.clone())
+        .expect_commit_failure();
+    env.disable_member_badge(&env.alice.clone(), "disable carol", env.carol.2.clone())
+        .expect_commit_failure();
+    assert!(env.get_inactive_members(INACTIVITY_PERIOD).is_empty());
+}
+
+#[test]
+fn test_disable_inactive_members_only_lists_inactive_badges() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_success();
+    env.advance_time(INACTIVITY_PERIOD + 1);
+
+    // Dave's badge is new, the signer is active
+    let dave_id = NonFungibleLocalId::integer(4);
+    env.disable_inactive_members(
+        &env.alice.clone(), "cleanup", INACTIVITY_PERIOD, vec![dave_id],
+    ).expect_commit_failure();
+    env.disable_inactive_members(
+        &env.alice.clone(), "cleanup", INACTIVITY_PERIOD, vec![env.alice.2.clone()],
+    ).expect_commit_failure();
+
+    // Carol becomes active after the operation has been proposed, it can't be executed anymore
+    env.disable_inactive_members(
+        &env.alice.clone(), "cleanup", INACTIVITY_PERIOD, vec![env.carol.2.clone()],
+    ).expect_commit_success();
+    env.mint_member_badge(&env.carol.clone(), "another badge", dave_addr).expect_commit_success();
+    env.disable_inactive_members(
+        &env.bob.clone(), "cleanup", INACTIVITY_PERIOD, vec![env.carol.2.clone()],
+    ).expect_commit_failure();
+}
+
+#[test]
+fn test_delegation_counts_as_activity() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let expiration = env.now().add_days(30).unwrap();
+
+    // Carol delegates to Alice, then Alice signs for both of them
+    env.delegate_signature(&env.carol.clone(), env.alice.2.clone(), expiration, None)
+        .expect_commit_success();
+    env.advance_time(INACTIVITY_PERIOD + 1);
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+
+    let inactive_ids: Vec<NonFungibleLocalId> = env.get_inactive_members(INACTIVITY_PERIOD)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(inactive_ids, vec![env.bob.2.clone()]);
+}
+
+#[test]
+fn test_disable_inactive_members_keeps_min_cosigners_enabled() {
+    let mut env = Helper::new_2_of_3();
+
+    // Only Alice would remain enabled
+    env.advance_time(INACTIVITY_PERIOD + 1);
+    env.disable_inactive_members(
+        &env.alice.clone(),
+        "cleanup",
+        INACTIVITY_PERIOD,
+        vec![env.bob.2.clone(), env.carol.2.clone()],
+    ).expect_commit_failure();
+}
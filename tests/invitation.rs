@@ -0,0 +1,158 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+
+const ONE_WEEK: i64 = 7 * 86400;
+
+#[test]
+fn test_invitation_to_account_rejecting_deposits() {
+    let mut env = Helper::new_2_of_3();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    env.reject_deposits(dave_addr, dave_pk);
+
+    // A direct mint can't be deposited
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_failure();
+
+    // An invitation can be claimed
+    let expiration = env.now().add_seconds(ONE_WEEK).unwrap();
+    env.invite_member(&env.alice.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.invite_member(&env.bob.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.claim_invitation(dave_addr, &dave_pk).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+
+    // The invitation can't be claimed twice
+    env.claim_invitation(dave_addr, &dave_pk).expect_commit_failure();
+}
+
+#[test]
+fn test_only_the_account_owner_can_claim() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let alice_pk = env.alice.1;
+    let expiration = env.now().add_seconds(ONE_WEEK).unwrap();
+
+    env.invite_member(&env.alice.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.invite_member(&env.bob.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.claim_invitation(dave_addr, &alice_pk).expect_commit_failure();
+}
+
+#[test]
+fn test_expired_invitation() {
+    let mut env = Helper::new_2_of_3();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let expiration = env.now().add_seconds(ONE_WEEK).unwrap();
+
+    env.invite_member(&env.alice.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.invite_member(&env.bob.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.advance_time(ONE_WEEK + 1);
+    env.claim_invitation(dave_addr, &dave_pk).expect_commit_failure();
+}
+
+#[test]
+fn test_revoked_invitation() {
+    let mut env = Helper::new_2_of_3();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let expiration = env.now().add_seconds(ONE_WEEK).unwrap();
+
+    env.invite_member(&env.alice.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.invite_member(&env.bob.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.revoke_invitation(&env.alice.clone(), "revoke dave", dave_addr).expect_commit_success();
+    env.revoke_invitation(&env.bob.clone(), "revoke dave", dave_addr).expect_commit_success();
+    env.claim_invitation(dave_addr, &dave_pk).expect_commit_failure();
+}
+
+#[test]
+fn test_expire_invitation() {
+    let mut env = Helper::new_2_of_3();
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let expiration = env.now().add_seconds(ONE_WEEK).unwrap();
+
+    env.invite_member(&env.alice.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    env.invite_member(&env.bob.clone(), "invite dave", dave_addr, expiration)
+        .expect_commit_success();
+    assert_eq!(env.get_invitation(dave_addr), Some(Some(expiration)));
+
+    // Too early to remove the invitation
+    env.expire_invitation(dave_addr).expect_commit_failure();
+
+    env.advance_time(ONE_WEEK + 1);
+    env.expire_invitation(dave_addr).expect_commit_success();
+    assert_eq!(env.get_invitation(dave_addr), None);
+    env.expire_invitation(dave_addr).expect_commit_failure();
+    env.claim_invitation(dave_addr, &dave_pk).expect_commit_failure();
+}
+
+#[test]
+fn test_founding_member_refusing_deposits_is_invited() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (pk_a, _, addr_a) = ledger.new_allocated_account();
+    let (_,    _, addr_b) = ledger.new_allocated_account();
+    let (pk_c, _, addr_c) = ledger.new_allocated_account();
+    let pkg = ledger.compile_and_publish(this_package!());
+
+    // Carol's account refuses third party deposits
+    ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                addr_c,
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+                AccountSetDefaultDepositRuleInput { default: DefaultDepositRule::Reject },
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(pk_c)],
+    ).expect_commit_success();
+
+    let receipt = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(pkg, "DaoWallet", "new",
+                manifest_args!(
+                    indexset![addr_a, addr_b, addr_c], 2usize,
+                    "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                    Vec::<String>::new(), None::<ComponentAddress>, 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                ))
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(pk_a)],
+    );
+    let commit = receipt.expect_commit(true);
+    let component = commit.new_component_addresses()[0];
+    let member_badge = commit.new_resource_addresses()[0];
+
+    // Bob and Alice got the first ids, Carol got an invitation that never expires
+    assert_eq!(ledger.get_component_balance(addr_b, member_badge), dec!("1"));
+    assert_eq!(ledger.get_component_balance(addr_a, member_badge), dec!("1"));
+    assert_eq!(ledger.get_component_balance(addr_c, member_badge), dec!("0"));
+    let invitation: Option<Option<Instant>> = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, "get_invitation", manifest_args!(addr_c))
+            .build(),
+        vec![],
+    ).expect_commit(true).output(1);
+    assert_eq!(invitation, Some(None));
+
+    // Carol claims her badge
+    ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, "claim_invitation", manifest_args!(addr_c))
+            .deposit_entire_worktop(addr_c)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(pk_c)],
+    ).expect_commit_success();
+    assert_eq!(ledger.get_component_balance(addr_c, member_badge), dec!("1"));
+}
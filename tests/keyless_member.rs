@@ -0,0 +1,178 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::OperationDetails;
+use helper::has_event;
+
+/// Alice and Bob register a keyless member; his badge gets id #4#.
+fn add_keyless_member(env: &mut Helper) -> (Secp256k1PublicKey, NonFungibleLocalId) {
+    let (pk, _) = env.ledger.new_key_pair();
+    let signer = NonFungibleGlobalId::from_public_key(pk);
+
+    env.mint_keyless_member_badge(&env.alice.clone(), "add cold key", signer.clone())
+        .expect_commit_success();
+    let r = env.mint_keyless_member_badge(&env.bob.clone(), "add cold key", signer);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+
+    (pk, NonFungibleLocalId::integer(4))
+}
+
+#[test]
+fn test_keyless_member_counts_toward_threshold() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let (pk, badge_id) = add_keyless_member(&mut env);
+
+    // Alice proposes, the keyless member signs with his key
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let r = env.sign_operation_as_keyless(
+        &pk, badge_id, "add dave", OperationDetails::MintBadge(dave_addr),
+    );
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_keyless_member_restrictions() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let (pk, badge_id) = add_keyless_member(&mut env);
+
+    // The same key can't be registered twice
+    let signer = NonFungibleGlobalId::from_public_key(pk);
+    env.mint_keyless_member_badge(&env.alice.clone(), "add cold key again", signer)
+        .expect_commit_failure();
+
+    // A transaction not signed with the key can't use the keyless member badge
+    let carol_pk = env.carol.1;
+    env.sign_operation_as_keyless(
+        &carol_pk, badge_id, "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_failure();
+}
+
+#[test]
+fn test_keyless_member_removes_signature() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let (pk, badge_id) = add_keyless_member(&mut env);
+
+    // The keyless member proposes, then changes his mind
+    env.sign_operation_as_keyless(
+        &pk, badge_id.clone(), "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_success();
+    env.remove_signature_as_keyless(
+        &pk, badge_id.clone(), "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_success();
+
+    // His signature no longer counts and can't be removed twice
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+    env.remove_signature_as_keyless(
+        &pk, badge_id.clone(), "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_failure();
+
+    // Only the key of the member can remove his signature
+    let carol_pk = env.carol.1;
+    env.sign_operation_as_keyless(
+        &pk, badge_id.clone(), "add dave again", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_success();
+    env.remove_signature_as_keyless(
+        &carol_pk, badge_id, "add dave again", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_failure();
+}
+
+#[test]
+fn test_keyless_member_delegates_signature() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let (pk, badge_id) = add_keyless_member(&mut env);
+    let expiration = env.now().add_days(30).unwrap();
+
+    // The keyless member delegates to Alice, Alice alone is enough
+    env.delegate_signature_as_keyless(&pk, badge_id.clone(), env.alice.2.clone(), expiration, None)
+        .expect_commit_success();
+    let r = env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+
+    // After the revocation Alice's signature counts once
+    env.revoke_delegation_as_keyless(&pk, badge_id).expect_commit_success();
+    env.mint_member_badge(&env.alice.clone(), "add dave again", dave_addr)
+        .expect_commit_success();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_keyless_member_spends_budget() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+    let dave_balance = env.ledger.get_component_balance(dave_addr, XRD);
+    let (pk, badge_id) = add_keyless_member(&mut env);
+    let expiration = env.now().add_days(30).unwrap();
+
+    env.grant_budget(&env.alice.clone(), "budget", badge_id.clone(), XRD, dec!("100"), 86400, expiration)
+        .expect_commit_success();
+    env.grant_budget(&env.bob.clone(), "budget", badge_id.clone(), XRD, dec!("100"), 86400, expiration)
+        .expect_commit_success();
+
+    let r = env.spend_budget_as_keyless(&pk, badge_id, XRD, dec!("60"), dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "BudgetSpentEvent"));
+    assert_eq!(env.ledger.get_component_balance(dave_addr, XRD), dave_balance + dec!("60"));
+}
+
+#[test]
+fn test_keyless_member_registers_bls_key() {
+    let mut env = Helper::new_2_of_3();
+    let (pk, badge_id) = add_keyless_member(&mut env);
+    let sk = Bls12381G1PrivateKey::from_u64(4).unwrap();
+    let public_key = sk.public_key();
+
+    // A wrong proof of possession is refused
+    let other_sk = Bls12381G1PrivateKey::from_u64(5).unwrap();
+    env.register_bls_key_as_keyless(
+        &pk, badge_id.clone(), public_key, other_sk.sign_v1(&public_key.0),
+    ).expect_commit_failure();
+
+    env.register_bls_key_as_keyless(&pk, badge_id, public_key, sk.sign_v1(&public_key.0))
+        .expect_commit_success();
+}
+
+#[test]
+fn test_keyless_member_pays_dues() {
+    let mut env = Helper::new_with_dues(2, 3, (XRD, dec!("10"), 30 * 86400), 7 * 86400);
+    let dave_addr = env.dave.0;
+    let (pk, badge_id) = add_keyless_member(&mut env);
+
+    // Two periods are paid, the exceeding 5 XRD go to Dave's account
+    let r = env.pay_dues_as_keyless(&pk, badge_id.clone(), dec!("25"), dave_addr);
+    r.expect_commit_success();
+    assert_eq!(env.ledger.get_component_balance(env.dao_account, XRD), dec!("20"));
+
+    // A transaction not signed with the key can't pay for the keyless member badge
+    let carol_pk = env.carol.1;
+    env.pay_dues_as_keyless(&carol_pk, badge_id, dec!("10"), dave_addr)
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_keyless_member_registers_account() {
+    let mut env = Helper::new_with_conflicts_of_interest_refused(2, 3);
+    let (dave_addr, dave_pk) = (env.dave.0, env.dave.1);
+    let (pk, badge_id) = add_keyless_member(&mut env);
+
+    // Only the owner of an account can register it
+    let carol_pk = env.carol.1;
+    env.register_account_as_keyless(&pk, badge_id.clone(), dave_addr, &carol_pk)
+        .expect_commit_failure();
+
+    // Once Dave's account is registered, the keyless member can't sign operations benefiting it
+    env.register_account_as_keyless(&pk, badge_id.clone(), dave_addr, &dave_pk)
+        .expect_commit_success();
+    env.sign_operation_as_keyless(
+        &pk, badge_id, "add dave", OperationDetails::MintBadge(dave_addr),
+    ).expect_commit_failure();
+}
@@ -0,0 +1,186 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::{Helper, ResourceAction};
+
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData)]
+struct Item {
+    name: String,
+    #[mutable]
+    level: u64,
+}
+
+#[test]
+fn test_manage_fungible_resource() {
+    let mut env = Helper::new_2_of_3();
+    let (dao_account, dave_addr) = (env.dao_account, env.dave.0);
+
+    // The DAO treasury holds the minter badge and the initial supply of the community token
+    let minter_badge = env.ledger.create_fungible_resource(dec!("1"), 0, dao_account);
+    let other_badge = env.ledger.create_fungible_resource(dec!("1"), 0, dao_account);
+    let token = env.ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_fungible_resource(
+                OwnerRole::None,
+                true,
+                18,
+                FungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(require(minter_badge));
+                        minter_updater => rule!(deny_all);
+                    },
+                    burn_roles: burn_roles! {
+                        burner => rule!(require(minter_badge));
+                        burner_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata! {
+                    roles {
+                        metadata_setter => rule!(require(minter_badge));
+                        metadata_setter_updater => rule!(deny_all);
+                        metadata_locker => rule!(deny_all);
+                        metadata_locker_updater => rule!(deny_all);
+                    }
+                },
+                Some(dec!("1000")),
+            )
+            .try_deposit_entire_worktop_or_abort(dao_account, None)
+            .build(),
+        vec![],
+    ).expect_commit(true).new_resource_addresses()[0];
+    let badge = ResourceSpecifier::Fungible(dec!("1"));
+
+    // Mint to dave
+    let mint = ResourceAction::MintFungibles(dec!("100"), dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", token, Some((minter_badge, badge.clone())), mint.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "mint", token, Some((minter_badge, badge.clone())), mint)
+        .expect_commit_success();
+    assert_eq!(env.balance(dave_addr, token), dec!("100"));
+
+    // Burn from the treasury
+    let burn = ResourceAction::BurnFungibles(dec!("10"));
+    env.manage_resource(&env.alice.clone(), "burn", token, Some((minter_badge, badge.clone())), burn.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "burn", token, Some((minter_badge, badge.clone())), burn)
+        .expect_commit_success();
+    assert_eq!(env.balance(dao_account, token), dec!("990"));
+
+    // Set a metadata
+    let set_metadata = ResourceAction::SetMetadata(
+        "symbol".to_owned(), MetadataValue::String("COMM".to_owned()),
+    );
+    env.manage_resource(&env.alice.clone(), "symbol", token, Some((minter_badge, badge.clone())), set_metadata.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "symbol", token, Some((minter_badge, badge.clone())), set_metadata)
+        .expect_commit_success();
+    assert_eq!(
+        env.ledger.get_metadata(token.into(), "symbol"),
+        Some(MetadataValue::String("COMM".to_owned())),
+    );
+
+    // A badge that doesn't satisfy the minter role can't be used
+    let mint = ResourceAction::MintFungibles(dec!("100"), dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", token, Some((other_badge, badge.clone())), mint.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "mint", token, Some((other_badge, badge)), mint)
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_manage_non_fungible_resource() {
+    let mut env = Helper::new_2_of_3();
+    let (dao_account, dave_addr) = (env.dao_account, env.dave.0);
+
+    // The DAO treasury holds the admin badge and the first item of the collection
+    let admin_badge = env.ledger.create_non_fungible_resource(dao_account);
+    let collection = env.ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_non_fungible_resource(
+                OwnerRole::None,
+                NonFungibleIdType::Integer,
+                true,
+                NonFungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(require(admin_badge));
+                        minter_updater => rule!(deny_all);
+                    },
+                    burn_roles: burn_roles! {
+                        burner => rule!(require(admin_badge));
+                        burner_updater => rule!(deny_all);
+                    },
+                    non_fungible_data_update_roles: non_fungible_data_update_roles! {
+                        non_fungible_data_updater => rule!(require(admin_badge));
+                        non_fungible_data_updater_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata!(),
+                Some([(NonFungibleLocalId::integer(1), Item { name: "Shield".to_owned(), level: 1 })]),
+            )
+            .try_deposit_entire_worktop_or_abort(dao_account, None)
+            .build(),
+        vec![],
+    ).expect_commit(true).new_resource_addresses()[0];
+    let badge = ResourceSpecifier::NonFungible(indexset![NonFungibleLocalId::integer(1)]);
+    let (shield, sword) = (NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2));
+
+    // Mint a new item to dave; an existing id can't be minted again
+    let data = scrypto_encode(&Item { name: "Sword".to_owned(), level: 1 }).unwrap();
+    let mint = ResourceAction::MintNonFungible(sword.clone(), data.clone(), dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint", collection, Some((admin_badge, badge.clone())), mint.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "mint", collection, Some((admin_badge, badge.clone())), mint)
+        .expect_commit_success();
+    assert_eq!(env.nft_ids(dave_addr, collection), vec![sword.clone()]);
+    let mint = ResourceAction::MintNonFungible(sword, data, dave_addr);
+    env.manage_resource(&env.alice.clone(), "mint again", collection, Some((admin_badge, badge.clone())), mint)
+        .expect_commit_failure();
+
+    // Level up the item in the treasury
+    let update = ResourceAction::UpdateNonFungibleData(
+        shield.clone(), "level".to_owned(), scrypto_encode(&5u64).unwrap(),
+    );
+    env.manage_resource(&env.alice.clone(), "level up", collection, Some((admin_badge, badge.clone())), update.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "level up", collection, Some((admin_badge, badge.clone())), update)
+        .expect_commit_success();
+    assert_eq!(env.ledger.get_non_fungible_data::<Item>(collection, shield.clone()).level, 5);
+
+    // Burn it
+    let burn = ResourceAction::BurnNonFungibles(vec![shield]);
+    env.manage_resource(&env.alice.clone(), "burn", collection, Some((admin_badge, badge.clone())), burn.clone())
+        .expect_commit_success();
+    env.manage_resource(&env.bob.clone(), "burn", collection, Some((admin_badge, badge)), burn)
+        .expect_commit_success();
+    assert_eq!(env.nft_count(dao_account, collection), 0);
+}
+
+#[test]
+fn test_cannot_manage_component_badges() {
+    let mut env = Helper::new_2_of_3();
+    let (member_badge, allowance_badge, dave_addr) =
+        (env.member_badge, env.allowance_badge, env.dave.0);
+
+    // Member badges can't be minted or updated bypassing their own operations
+    let mint = ResourceAction::MintNonFungible(
+        NonFungibleLocalId::integer(10), scrypto_encode(&()).unwrap(), dave_addr,
+    );
+    env.manage_resource(&env.alice.clone(), "new member", member_badge, None, mint)
+        .expect_commit_failure();
+    let update = ResourceAction::UpdateNonFungibleData(
+        env.carol.2.clone(), "enabled".to_owned(), scrypto_encode(&false).unwrap(),
+    );
+    env.manage_resource(&env.alice.clone(), "disable carol", member_badge, None, update)
+        .expect_commit_failure();
+
+    // Neither can allowance badges
+    let metadata = ResourceAction::SetMetadata(
+        "name".to_owned(), MetadataValue::String("Free money".to_owned()),
+    );
+    env.manage_resource(&env.alice.clone(), "rename", allowance_badge, None, metadata)
+        .expect_commit_failure();
+}
@@ -0,0 +1,100 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+#[test]
+fn test_member_badge_metadata_set_at_instantiation() {
+    let mut env = Helper::new_2_of_3();
+
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "name"),
+        Some(MetadataValue::String("DAO wallet badge".to_string()))
+    );
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "symbol"),
+        Some(MetadataValue::String("DAOW".to_string()))
+    );
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "icon_url"),
+        Some(MetadataValue::Url(UncheckedUrl::of("https://example.com/badge.png")))
+    );
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "tags"),
+        Some(MetadataValue::StringArray(vec!["dao".to_string()]))
+    );
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "dapp_definitions"),
+        None
+    );
+}
+
+#[test]
+fn test_dapp_definition_set_at_instantiation() {
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (pk_a, _, addr_a) = ledger.new_allocated_account();
+    let (_,    _, addr_b) = ledger.new_allocated_account();
+    let (_,    _, dapp_definition) = ledger.new_allocated_account();
+    let pkg = ledger.compile_and_publish(this_package!());
+    let receipt = ledger.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(pkg, "DaoWallet", "new",
+                manifest_args!(
+                    indexset![addr_a, addr_b], 2usize,
+                    "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                    Vec::<String>::new(), Some(dapp_definition), 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                ))
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(pk_a)],
+    );
+    let member_badge = receipt.expect_commit(true).new_resource_addresses()[0];
+
+    assert_eq!(
+        ledger.get_metadata(member_badge.into(), "dapp_definitions"),
+        Some(MetadataValue::GlobalAddressArray(vec![dapp_definition.into()]))
+    );
+}
+
+#[test]
+fn test_set_member_badge_metadata() {
+    let mut env = Helper::new_2_of_3();
+    let value = MetadataValue::String("Our DAO badge".to_string());
+
+    let r = env.set_member_badge_metadata(&env.alice.clone(), "rename", "name", value.clone());
+    r.expect_commit_success();
+    assert!(!has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "name"),
+        Some(MetadataValue::String("DAO wallet badge".to_string())),
+        "Metadata updated with a single signature"
+    );
+
+    let r = env.set_member_badge_metadata(&env.bob.clone(), "rename", "name", value.clone());
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(
+        env.ledger.get_metadata(env.member_badge.into(), "name"),
+        Some(value)
+    );
+}
+
+#[test]
+fn test_member_badge_metadata_not_settable_directly() {
+    let mut env = Helper::new_2_of_3();
+    let alice_pk = env.alice.1;
+    let member_badge = env.member_badge;
+
+    env.ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .set_metadata(member_badge, "name", MetadataValue::String("hack".to_string()))
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(alice_pk)],
+        )
+        .expect_commit_failure();
+}
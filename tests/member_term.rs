@@ -0,0 +1,96 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const MEMBER_TERM: i64 = 86400;
+
+// Alice and Bob mint a member badge for Dave, it's the only one with a term
+fn add_dave(env: &mut Helper) -> (ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId) {
+    let dave_addr = env.dave.0;
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr).expect_commit_success();
+    let badge_id = env.nft_ids(dave_addr, env.member_badge).remove(0);
+
+    (dave_addr, env.dave.1, badge_id)
+}
+
+#[test]
+fn test_expired_badge_cannot_sign() {
+    let mut env = Helper::new_with_term(2, 3, MEMBER_TERM);
+    let dave = add_dave(&mut env);
+
+    env.advance_time(MEMBER_TERM + 1);
+    env.disable_member_badge(&dave, "disable carol", env.carol.2.clone())
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_founding_members_never_expire() {
+    let mut env = Helper::new_with_term(2, 3, MEMBER_TERM);
+    let dave_addr = env.dave.0;
+
+    // The whole term elapses, the founding members can still operate the DAO
+    env.advance_time(MEMBER_TERM + 1);
+    let r = env.expire_members();
+    r.expect_commit_success();
+    assert!(!has_event(&r, "MemberExpiredEvent"));
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let r = env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_renew_member() {
+    let mut env = Helper::new_with_term(2, 3, MEMBER_TERM);
+    let dave = add_dave(&mut env);
+    let valid_until = env.now().add_days(365).unwrap();
+
+    env.renew_member(&env.alice.clone(), "renew dave", dave.2.clone(), valid_until)
+        .expect_commit_success();
+    let r = env.renew_member(&env.bob.clone(), "renew dave", dave.2.clone(), valid_until);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+
+    // Dave has been renewed, he can still sign after the original term
+    env.advance_time(MEMBER_TERM + 1);
+    env.disable_member_badge(&dave, "disable carol", env.carol.2.clone())
+        .expect_commit_success();
+}
+
+#[test]
+fn test_renew_member_in_the_past_fails() {
+    let mut env = Helper::new_with_term(2, 3, MEMBER_TERM);
+    let yesterday = env.now().add_days(-1).unwrap();
+
+    env.renew_member(&env.alice.clone(), "renew bob", env.bob.2.clone(), yesterday)
+        .expect_commit_failure();
+}
+
+#[test]
+fn test_expire_members() {
+    let mut env = Helper::new_with_term(2, 3, MEMBER_TERM);
+    let dave = add_dave(&mut env);
+
+    // Nobody expired yet
+    let r = env.expire_members();
+    r.expect_commit_success();
+    assert!(!has_event(&r, "MemberExpiredEvent"));
+
+    // Dave's term is over
+    env.advance_time(MEMBER_TERM + 1);
+    let r = env.expire_members();
+    r.expect_commit_success();
+    assert!(has_event(&r, "MemberExpiredEvent"));
+    let disabled: Vec<NonFungibleLocalId> = r.expect_commit(true).output(1);
+    assert_eq!(disabled, vec![dave.2.clone()]);
+
+    // Dave's badge is now disabled and it would need an EnableBadge operation after renewal
+    env.disable_member_badge(&env.alice.clone(), "disable dave", dave.2.clone())
+        .expect_commit_failure();
+    env.enable_member_badge(&env.alice.clone(), "enable dave", dave.2.clone())
+        .expect_commit_success();
+}
@@ -100,7 +100,7 @@ fn test_new_member_can_cosign_after_badge_mint() {
                 (l.proof("p"), "dave pays alice".to_owned(), XRD, dec!("10"), alice_addr)
             })
             .build(),
-        vec![NonFungibleGlobalId::from_public_key(&dave_pk)],
+        vec![NonFungibleGlobalId::from_public_key(dave_pk)],
     );
     receipt.expect_commit_success();
     assert!(has_event(&receipt, "NewOperationEvent"));
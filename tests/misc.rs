@@ -63,7 +63,7 @@ fn test_wrong_badge_resource_rejected() {
                     (l.proof("p"), "hack".to_owned())
                 })
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&alice_pk)],
+            vec![NonFungibleGlobalId::from_public_key(alice_pk)],
         )
         .expect_commit_failure();
 }
@@ -137,11 +137,12 @@ fn test_same_description_different_recipient_is_different_op() {
     let dao_initial_balance = env.xrd_balance(env.dao_account);
 
     // Same description + amount, different recipient → different op hash
-    let r = env.send_fungibles(&env.alice.clone(), "pay 100", XRD, dec!("100"), alice_addr);
+    // (Carol proposes, Alice signing a payment to herself would be a conflict of interest)
+    let r = env.send_fungibles(&env.carol.clone(), "pay 100", XRD, dec!("100"), alice_addr);
     r.expect_commit_success();
     assert!(has_event(&r, "NewOperationEvent"));
     assert!(!has_event(&r, "OperationExecutedEvent"));
-    let r = env.send_fungibles(&env.alice.clone(), "pay 100", XRD, dec!("100"), dave_addr);
+    let r = env.send_fungibles(&env.carol.clone(), "pay 100", XRD, dec!("100"), dave_addr);
     r.expect_commit_success();
     assert!(has_event(&r, "NewOperationEvent"));
     assert!(!has_event(&r, "OperationExecutedEvent"));
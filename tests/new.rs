@@ -30,9 +30,15 @@ fn test_new_rejects_zero_min_cosigners() {
             ManifestBuilder::new()
                 .lock_fee_from_faucet()
                 .call_function(pkg, "DaoWallet", "new",
-                    manifest_args!(indexset![addr], 0usize))
+                    manifest_args!(
+                        indexset![addr], 0usize,
+                        "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                        Vec::<String>::new(), None::<ComponentAddress>, 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                    ))
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&pk)],
+            vec![NonFungibleGlobalId::from_public_key(pk)],
         )
         .expect_commit_failure();
 }
@@ -47,9 +53,15 @@ fn test_new_rejects_min_cosigners_of_one() {
             ManifestBuilder::new()
                 .lock_fee_from_faucet()
                 .call_function(pkg, "DaoWallet", "new",
-                    manifest_args!(indexset![addr], 1usize))
+                    manifest_args!(
+                        indexset![addr], 1usize,
+                        "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                        Vec::<String>::new(), None::<ComponentAddress>, 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                    ))
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&pk)],
+            vec![NonFungibleGlobalId::from_public_key(pk)],
         )
         .expect_commit_failure();
 }
@@ -64,9 +76,15 @@ fn test_new_rejects_min_cosigners_above_member_count() {
             ManifestBuilder::new()
                 .lock_fee_from_faucet()
                 .call_function(pkg, "DaoWallet", "new",
-                    manifest_args!(indexset![addr], 5usize))
+                    manifest_args!(
+                        indexset![addr], 5usize,
+                        "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                        Vec::<String>::new(), None::<ComponentAddress>, 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                    ))
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&pk)],
+            vec![NonFungibleGlobalId::from_public_key(pk)],
         )
         .expect_commit_failure();
 }
@@ -82,9 +100,15 @@ fn test_new_minimum_valid_2_of_2() {
             ManifestBuilder::new()
                 .lock_fee_from_faucet()
                 .call_function(pkg, "DaoWallet", "new",
-                    manifest_args!(indexset![addr_a, addr_b], 2usize))
+                    manifest_args!(
+                        indexset![addr_a, addr_b], 2usize,
+                        "DAO wallet badge", "DAOW", "", "https://example.com/badge.png",
+                        Vec::<String>::new(), None::<ComponentAddress>, 0i64, None::<i64>,
+                        dec!(0), 604800i64, false, None::<(ResourceAddress, Decimal, i64)>, 0i64,
+                        false, false
+                    ))
                 .build(),
-            vec![NonFungibleGlobalId::from_public_key(&pk_a)],
+            vec![NonFungibleGlobalId::from_public_key(pk_a)],
         )
         .expect_commit_success();
 }
@@ -0,0 +1,27 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::OperationDetails;
+use helper::event_data;
+
+#[test]
+fn test_operation_hash_in_events_and_outputs() {
+    let mut env = Helper::new_2_of_3();
+    let dave_addr = env.dave.0;
+    let operation_hash = env.get_operation_hash(
+        "add dave", OperationDetails::MintBadge(dave_addr),
+    );
+
+    // Both signers get the same hash, the events end with it
+    let r = env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr);
+    assert_eq!(r.expect_commit_success().output::<Hash>(3), operation_hash);
+    assert!(event_data(&r, "NewOperationEvent").ends_with(&operation_hash.0));
+
+    let r = env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr);
+    assert_eq!(r.expect_commit_success().output::<Hash>(3), operation_hash);
+    assert!(event_data(&r, "OperationExecutedEvent").ends_with(&operation_hash.0));
+
+    // A different operation has a different hash
+    let r = env.mint_member_badge(&env.alice.clone(), "add dave again", dave_addr);
+    assert_ne!(r.expect_commit_success().output::<Hash>(3), operation_hash);
+}
@@ -0,0 +1,77 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::{Helper, ConstantProductPoolHelper, event_data};
+
+// Resources and amounts deposited in the DAO treasury according to the OperationExecutedEvent;
+// they come just before the cosigners and the operation hash
+fn received(receipt: &TransactionReceiptV1) -> Vec<(ResourceAddress, Decimal)> {
+    match scrypto_decode::<ScryptoValue>(&event_data(receipt, "OperationExecutedEvent")).unwrap() {
+        ScryptoValue::Tuple { fields } =>
+            scrypto_decode(&scrypto_encode(&fields[fields.len() - 3]).unwrap()).unwrap(),
+        _ => panic!("Unexpected event data"),
+    }
+}
+
+#[test]
+fn test_two_resource_pool() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dao_account = env.dao_account;
+    let token = env.ledger.create_fungible_resource(dec!("1000"), 18, dao_account);
+    let (pool, pool_units) = env.new_native_pool("TwoResourcePool", (XRD, token));
+    let xrd_before = env.xrd_balance(dao_account);
+
+    // The contributions must match the resources of the pool, in any order
+    env.contribute_to_pool(&env.alice.clone(), "wrong", pool, vec![(XRD, dec!("100"))])
+        .expect_commit_failure();
+    let contributions = vec![(token, dec!("50")), (XRD, dec!("100"))];
+    env.contribute_to_pool(&env.alice.clone(), "add liquidity", pool, contributions.clone())
+        .expect_commit_success();
+    let r = env.contribute_to_pool(&env.bob.clone(), "add liquidity", pool, contributions);
+    r.expect_commit_success();
+    let units = env.balance(dao_account, pool_units);
+    assert!(units > Decimal::ZERO);
+    assert_eq!(received(&r), vec![(pool_units, units)]);
+    assert_eq!(xrd_before - env.xrd_balance(dao_account), dec!("100"));
+    assert_eq!(env.balance(dao_account, token), dec!("950"));
+
+    // Redeem all of the pool units
+    env.redeem_from_pool(&env.alice.clone(), "remove liquidity", pool, units)
+        .expect_commit_success();
+    let r = env.redeem_from_pool(&env.bob.clone(), "remove liquidity", pool, units);
+    r.expect_commit_success();
+    assert_eq!(received(&r), vec![(XRD, dec!("100")), (token, dec!("50"))]);
+    assert_eq!(env.balance(dao_account, pool_units), Decimal::ZERO);
+    assert_eq!(env.xrd_balance(dao_account), xrd_before);
+    assert_eq!(env.balance(dao_account, token), dec!("1000"));
+}
+
+#[test]
+fn test_one_resource_pool() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let dao_account = env.dao_account;
+    let (pool, pool_units) = env.new_native_pool("OneResourcePool", XRD);
+
+    // Only native pools are supported
+    let other_pool = ConstantProductPoolHelper::new(&mut env).component;
+    env.contribute_to_pool(&env.alice.clone(), "not native", other_pool, vec![(XRD, dec!("100"))])
+        .expect_commit_failure();
+
+    env.contribute_to_pool(&env.alice.clone(), "add liquidity", pool, vec![(XRD, dec!("100"))])
+        .expect_commit_success();
+    let r = env.contribute_to_pool(&env.bob.clone(), "add liquidity", pool, vec![(XRD, dec!("100"))]);
+    r.expect_commit_success();
+    assert_eq!(received(&r), vec![(pool_units, dec!("100"))]);
+
+    // Only available pool units can be redeemed
+    env.redeem_from_pool(&env.alice.clone(), "too much", pool, dec!("101"))
+        .expect_commit_failure();
+    env.redeem_from_pool(&env.alice.clone(), "remove liquidity", pool, dec!("40"))
+        .expect_commit_success();
+    let r = env.redeem_from_pool(&env.bob.clone(), "remove liquidity", pool, dec!("40"));
+    r.expect_commit_success();
+    assert_eq!(received(&r), vec![(XRD, dec!("40"))]);
+    assert_eq!(env.balance(dao_account, pool_units), dec!("60"));
+}
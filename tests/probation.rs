@@ -0,0 +1,62 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+const PROBATION_PERIOD: i64 = 86400;
+
+#[test]
+fn test_new_member_signature_does_not_count_during_probation() {
+    let mut env = Helper::new_with_probation(2, 3, PROBATION_PERIOD);
+    env.fund_dao();
+    let dave_addr = env.dave.0;
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(),   "add dave", dave_addr).expect_commit_success();
+    let dave = (dave_addr, env.dave.1, NonFungibleLocalId::integer(4));
+
+    // Dave can propose an operation
+    let r = env.send_fungibles(&dave, "pay alice", XRD, dec!("10"), env.alice.0);
+    r.expect_commit_success();
+    assert!(has_event(&r, "NewOperationEvent"));
+
+    // Alice cosigns but Dave's signature doesn't count yet
+    let r = env.send_fungibles(&env.alice.clone(), "pay alice", XRD, dec!("10"), env.alice.0);
+    r.expect_commit_success();
+    assert!(!has_event(&r, "OperationExecutedEvent"));
+
+    // Once the probation is over Dave's signature counts
+    env.advance_time(PROBATION_PERIOD + 1);
+    env.send_fungibles(&env.alice.clone(), "pay bob", XRD, dec!("10"), env.bob.0)
+        .expect_commit_success();
+    let r = env.send_fungibles(&dave, "pay bob", XRD, dec!("10"), env.bob.0);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_founding_members_are_not_on_probation() {
+    let mut env = Helper::new_with_probation(2, 3, PROBATION_PERIOD);
+    let dave_addr = env.dave.0;
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    let r = env.mint_member_badge(&env.bob.clone(), "add dave", dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+}
+
+#[test]
+fn test_member_on_probation_not_counted_for_threshold_invariants() {
+    let mut env = Helper::new_with_probation(3, 3, PROBATION_PERIOD);
+    let dave_addr = env.dave.0;
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.bob.clone(),   "add dave", dave_addr).expect_commit_success();
+    env.mint_member_badge(&env.carol.clone(), "add dave", dave_addr).expect_commit_success();
+
+    // Only three members can cosign, the threshold can't be increased
+    env.increase_min_cosigners(&env.alice.clone(), "4 of 4").expect_commit_failure();
+
+    env.advance_time(PROBATION_PERIOD + 1);
+    env.increase_min_cosigners(&env.alice.clone(), "4 of 4").expect_commit_success();
+}
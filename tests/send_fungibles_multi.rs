@@ -0,0 +1,64 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::has_event;
+
+#[test]
+fn test_send_fungibles_multi() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, carol_addr, dao_account) = (env.dave.0, env.carol.0, env.dao_account);
+    let (dave_before, carol_before) = (env.xrd_balance(dave_addr), env.xrd_balance(carol_addr));
+    let payments = vec![(dave_addr, dec!("100")), (carol_addr, dec!("50"))];
+
+    // The total must be available in the treasury
+    env.send_fungibles_multi(
+        &env.alice.clone(), "too much", XRD, vec![(dave_addr, dec!("10000")), (carol_addr, dec!("1"))],
+    ).expect_commit_failure();
+
+    env.send_fungibles_multi(&env.alice.clone(), "payroll", XRD, payments.clone())
+        .expect_commit_success();
+    assert_eq!(env.xrd_balance(dave_addr), dave_before, "Not yet executed");
+
+    let dao_before = env.xrd_balance(dao_account);
+    env.send_fungibles_multi(&env.bob.clone(), "payroll", XRD, payments)
+        .expect_commit_success();
+    assert_eq!(env.xrd_balance(dave_addr) - dave_before, dec!("100"));
+    assert_eq!(env.xrd_balance(carol_addr) - carol_before, dec!("50"));
+    assert_eq!(dao_before - env.xrd_balance(dao_account), dec!("150"));
+}
+
+#[test]
+fn test_send_fungibles_multi_refused_deposit() {
+
+    // By default the refused payment is stored in the locker
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let (dave_addr, dave_pk, carol_addr, dao_account) =
+        (env.dave.0, env.dave.1, env.carol.0, env.dao_account);
+    env.reject_deposits(dave_addr, dave_pk);
+    let (dave_before, carol_before) = (env.xrd_balance(dave_addr), env.xrd_balance(carol_addr));
+    let payments = vec![(dave_addr, dec!("100")), (carol_addr, dec!("50"))];
+
+    env.send_fungibles_multi(&env.alice.clone(), "payroll", XRD, payments.clone())
+        .expect_commit_success();
+    let dao_before = env.xrd_balance(dao_account);
+    let r = env.send_fungibles_multi(&env.bob.clone(), "payroll", XRD, payments.clone());
+    r.expect_commit_success();
+    assert!(has_event(&r, "PaymentStoredEvent"));
+    assert_eq!(env.xrd_balance(dave_addr), dave_before);
+    assert_eq!(env.xrd_balance(carol_addr) - carol_before, dec!("50"));
+    assert_eq!(dao_before - env.xrd_balance(dao_account), dec!("150"));
+
+    // With abort_refused_deposits the whole payout fails
+    let mut env = Helper::new_with_refused_deposits_aborted(2, 3);
+    env.fund_dao();
+    let (dave_addr, dave_pk, carol_addr) = (env.dave.0, env.dave.1, env.carol.0);
+    env.reject_deposits(dave_addr, dave_pk);
+    let payments = vec![(dave_addr, dec!("100")), (carol_addr, dec!("50"))];
+
+    env.send_fungibles_multi(&env.alice.clone(), "payroll", XRD, payments.clone())
+        .expect_commit_success();
+    env.send_fungibles_multi(&env.bob.clone(), "payroll", XRD, payments)
+        .expect_commit_failure();
+}
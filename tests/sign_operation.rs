@@ -0,0 +1,53 @@
+use scrypto::prelude::*;
+use scrypto_test::prelude::*;
+mod helper;
+use helper::Helper;
+use helper::OperationDetails;
+use helper::has_event;
+
+fn sign_mint_badge(
+    env: &mut Helper,
+    callers: &[&(ComponentAddress, Secp256k1PublicKey, NonFungibleLocalId)],
+    recipient: ComponentAddress,
+) -> TransactionReceiptV1 {
+    env.sign_operation(
+        callers, "add dave", OperationDetails::MintBadge(recipient),
+    )
+}
+
+#[test]
+fn test_sign_operation_with_multiple_badges() {
+    let mut env = Helper::new_3_of_3();
+    let dave_addr = env.dave.0;
+    let (bob, carol) = (env.bob.clone(), env.carol.clone());
+
+    env.mint_member_badge(&env.alice.clone(), "add dave", dave_addr).expect_commit_success();
+
+    // Bob and Carol sign in the same transaction
+    let r = sign_mint_badge(&mut env, &[&bob, &carol], dave_addr);
+    r.expect_commit_success();
+    assert!(has_event(&r, "OperationExecutedEvent"));
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 1);
+}
+
+#[test]
+fn test_sign_operation_restrictions() {
+    let mut env = Helper::new_3_of_3();
+    let dave_addr = env.dave.0;
+    let (alice, bob) = (env.alice.clone(), env.bob.clone());
+
+    // The member badge to disable must exist
+    env.sign_operation(
+        &[&alice], "disable ghost", OperationDetails::DisableBadge(NonFungibleLocalId::integer(99)),
+    ).expect_commit_failure();
+
+    // The operation must be possible
+    env.sign_operation(
+        &[&alice], "pay dave", OperationDetails::SendFungibles(XRD, dec!(1000), dave_addr),
+    ).expect_commit_failure();
+
+    // Alice can't sign twice
+    sign_mint_badge(&mut env, &[&alice, &bob], dave_addr).expect_commit_success();
+    env.mint_member_badge(&alice, "add dave", dave_addr).expect_commit_failure();
+    assert_eq!(env.nft_count(dave_addr, env.member_badge), 0);
+}
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+mod helper;
+use helper::{Helper, ConstantProductPoolHelper};
+
+#[test]
+fn test_swap() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let pool = ConstantProductPoolHelper::new(&mut env);
+    let (pool_address, token) = (pool.component, pool.token);
+    let dao_account = env.dao_account;
+    let xrd_before = env.xrd_balance(dao_account);
+    let token_before = env.balance(dao_account, token);
+
+    env.swap(&env.alice.clone(), "buy tokens", pool_address, XRD, dec!("100"), token, dec!("90"))
+        .expect_commit_success();
+    env.swap(&env.bob.clone(), "buy tokens", pool_address, XRD, dec!("100"), token, dec!("90"))
+        .expect_commit_success();
+
+    // 1000 * 100 / (1000 + 100) tokens are in the treasury
+    assert_eq!(xrd_before - env.xrd_balance(dao_account), dec!("100"));
+    assert!(env.balance(dao_account, token) - token_before > dec!("90.9"));
+}
+
+#[test]
+fn test_swap_slippage_protection() {
+    let mut env = Helper::new_2_of_3();
+    env.fund_dao();
+    let pool = ConstantProductPoolHelper::new(&mut env);
+    let (pool_address, token) = (pool.component, pool.token);
+
+    // A minimum output is required
+    env.swap(&env.alice.clone(), "no minimum", pool_address, XRD, dec!("100"), token, dec!("0"))
+        .expect_commit_failure();
+
+    // The pool gives less than the minimum output
+    env.swap(&env.alice.clone(), "buy tokens", pool_address, XRD, dec!("100"), token, dec!("95"))
+        .expect_commit_success();
+    env.swap(&env.bob.clone(), "buy tokens", pool_address, XRD, dec!("100"), token, dec!("95"))
+        .expect_commit_failure();
+}